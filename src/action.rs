@@ -12,8 +12,16 @@ pub enum BattleClientGuiAction<'d, ID> {
     Action(ClientMove<ID>),
     Faint,
     Catch,
-    SetExp(Level, Experience, Vec<&'d Move>),
-    LevelUp(Vec<&'d Move>),
+    /// Previous level, experience gained and moves learned by the active pokemon, and the opponent it defeated.
+    SetExp(Level, Experience, Vec<&'d Move>, Option<PokemonIdentifier<ID>>),
+    /// Splits experience from a defeated opponent among benched participants and Exp. Share holders.
+    ShareExp(Experience, Option<PokemonIdentifier<ID>>),
+    /// Experience gained by the party member at an index that is not on the field.
+    BenchExp(usize, Experience),
+    /// The party member at an index reaching a level.
+    LevelStats(usize, Level),
+    /// Moves learned by the party member at an index, and if the server knows of its level up.
+    LevelUp(usize, Vec<&'d Move>, bool),
     Replace(Option<usize>),
 }
 
@@ -26,7 +34,11 @@ pub enum BattleClientGuiCurrent<ID> {
     Catch,
     Replace(bool),
     SetExp,
-    LevelUp,
+    BenchExp,
+    LevelStats,
+    LevelUp(usize, bool),
+    /// A trainer speaking between actions.
+    Dialogue,
 }
 
 impl<'d, ID> BattleClientGuiAction<'d, ID> {
@@ -0,0 +1,195 @@
+use std::{
+    fmt::{Display, Write},
+    fs::read_to_string,
+    path::Path,
+};
+
+use hashbrown::HashMap;
+use serde::Deserialize;
+
+/// Named parameters filled into a message, such as `("pokemon", &name)` for `{pokemon}`.
+pub type Arguments<'a> = [(&'a str, &'a dyn Display)];
+
+/// A message, or variants of it chosen by grammar or count.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CatalogMessage {
+    Text(String),
+    Variants(HashMap<String, String>),
+}
+
+/// Battle messages keyed by id. Line breaks in a message start a new line in the message box.
+/// The English messages are built in, and locale files loaded over them replace the messages they define.
+#[derive(Debug, Clone)]
+pub struct BattleCatalog {
+    messages: HashMap<String, CatalogMessage>,
+}
+
+impl Default for BattleCatalog {
+    fn default() -> Self {
+        let mut catalog = Self {
+            messages: HashMap::new(),
+        };
+        if let Err(err) = catalog.parse(include_str!("../assets/text/en.ron")) {
+            log::warn!("Could not parse built in battle messages with error {}", err);
+        }
+        catalog
+    }
+}
+
+impl BattleCatalog {
+    /// Loads the messages in a locale file.
+    pub fn load(&mut self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        match read_to_string(path) {
+            Ok(data) => {
+                if let Err(err) = self.parse(&data) {
+                    log::warn!("Could not parse battle messages at {:?} with error {}", path, err);
+                }
+            }
+            Err(err) => log::warn!("Could not read battle messages at {:?} with error {}", path, err),
+        }
+    }
+
+    pub fn parse(&mut self, data: &str) -> Result<(), ron::Error> {
+        let messages: HashMap<String, CatalogMessage> = ron::from_str(data)?;
+        self.messages.extend(messages);
+        Ok(())
+    }
+
+    pub fn get(&self, id: &str, args: &Arguments) -> String {
+        self.variant(id, "other", args)
+    }
+
+    /// Gets a variant of a message, falling back to the `other` variant.
+    pub fn variant(&self, id: &str, variant: &str, args: &Arguments) -> String {
+        let message = match self.messages.get(id) {
+            Some(CatalogMessage::Text(text)) => Some(text),
+            Some(CatalogMessage::Variants(variants)) => variants.get(variant).or_else(|| variants.get("other")),
+            None => None,
+        };
+        match message {
+            Some(message) => Self::format(message, args),
+            None => {
+                log::warn!("Could not find battle message {} ({})", id, variant);
+                id.to_owned()
+            }
+        }
+    }
+
+    /// Gets the `zero`, `one` or `other` variant of a message for a count.
+    pub fn plural(&self, id: &str, count: u64, args: &Arguments) -> String {
+        self.variant(
+            id,
+            match count {
+                0 => "zero",
+                1 => "one",
+                _ => "other",
+            },
+            args,
+        )
+    }
+
+    /// Gets the name for a key from a message of names, or the key itself if it has no name.
+    pub fn name(&self, id: &str, key: &str) -> String {
+        self.find(id, key).unwrap_or_else(|| key.to_owned())
+    }
+
+    /// Gets the entry for a key from a message of names, such as a move's description, if it has one.
+    pub fn find(&self, id: &str, key: &str) -> Option<String> {
+        match self.messages.get(id) {
+            Some(CatalogMessage::Variants(names)) => names.get(key).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Joins items into a list, such as "A, B and C".
+    pub fn list<S: AsRef<str>>(&self, items: &[S]) -> String {
+        match items {
+            [] => String::new(),
+            [item] => item.as_ref().to_owned(),
+            [first, last] => self.variant("list", "two", &[("first", &first.as_ref()), ("last", &last.as_ref())]),
+            [rest @ .., last] => {
+                let separator = self.variant("list", "separator", &[]);
+                let rest = rest.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(&separator);
+                self.variant("list", "last", &[("rest", &rest), ("last", &last.as_ref())])
+            }
+        }
+    }
+
+    fn format(message: &str, args: &Arguments) -> String {
+        let mut string = String::with_capacity(message.len());
+        let mut rest = message;
+        while let Some(start) = rest.find('{') {
+            string.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+            match rest.find('}') {
+                Some(end) => {
+                    let name = &rest[..end];
+                    match args.iter().find(|(arg, ..)| *arg == name) {
+                        Some((.., value)) => {
+                            let _ = write!(string, "{}", value);
+                        }
+                        None => {
+                            string.push('{');
+                            string.push_str(name);
+                            string.push('}');
+                        }
+                    }
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    string.push('{');
+                    break;
+                }
+            }
+        }
+        string.push_str(rest);
+        string
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_fills_named_arguments() {
+        assert_eq!(BattleCatalog::format("{a} and {b}", &[("a", &1), ("b", &"two")]), "1 and two");
+    }
+
+    #[test]
+    fn format_keeps_unknown_placeholders() {
+        assert_eq!(BattleCatalog::format("{missing}!", &[("a", &1)]), "{missing}!");
+        assert_eq!(BattleCatalog::format("a {b", &[("b", &1)]), "a {b");
+    }
+
+    #[test]
+    fn plural_picks_variant_by_count() {
+        let catalog = BattleCatalog::default();
+        let args: &Arguments = &[("pokemon", &"A"), ("experience", &1)];
+        assert_eq!(catalog.plural("exp.gain", 1, args), "A gained 1 EXP. point!");
+        // there is no zero variant, so it falls back to other
+        assert_eq!(catalog.plural("exp.gain", 0, args), "A gained 1 EXP. points!");
+    }
+
+    #[test]
+    fn missing_messages_give_their_id() {
+        assert_eq!(BattleCatalog::default().get("not.a.message", &[]), "not.a.message");
+    }
+
+    #[test]
+    fn finds_move_descriptions() {
+        let catalog = BattleCatalog::default();
+        assert!(catalog.find("move.descriptions", "tackle").is_some());
+        assert_eq!(catalog.find("move.descriptions", "not-a-move"), None);
+    }
+
+    #[test]
+    fn list_joins_items() {
+        let catalog = BattleCatalog::default();
+        assert_eq!(catalog.list(&["A"]), "A");
+        assert_eq!(catalog.list(&["A", "B"]), "A and B");
+        assert_eq!(catalog.list(&["A", "B", "C"]), "A, B and C");
+    }
+}
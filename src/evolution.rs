@@ -0,0 +1,40 @@
+use hashbrown::HashMap;
+use serde::Deserialize;
+
+use pokedex::pokemon::{owned::OwnedPokemon, Level, Pokemon, PokemonId};
+
+/// Evolutions by level, keyed by the species that evolves. Filled in by the host.
+pub type Evolutions = HashMap<PokemonId, LevelEvolution>;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct LevelEvolution {
+    pub level: Level,
+    pub into: PokemonId,
+}
+
+/// A party pokemon that reached its evolution level during the battle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PendingEvolution {
+    /// Index of the pokemon in the party.
+    pub index: usize,
+    pub into: PokemonId,
+}
+
+/// Queues an evolution if a party pokemon has reached its evolution level.
+pub(crate) fn check(evolutions: &Evolutions, pending: &mut Vec<PendingEvolution>, index: usize, pokemon: &OwnedPokemon) {
+    if let Some(evolution) = evolutions.get(&pokemon.pokemon.id) {
+        if pokemon.level >= evolution.level && !pending.iter().any(|pending| pending.index == index) {
+            pending.push(PendingEvolution {
+                index,
+                into: evolution.into,
+            });
+        }
+    }
+}
+
+/// Changes a pokemon's species. Its stats follow the new species and it keeps the damage it had taken.
+pub(crate) fn evolve<'d>(pokemon: &mut OwnedPokemon<'d>, into: &'d Pokemon) {
+    let damage = pokemon.max_hp().saturating_sub(pokemon.hp);
+    pokemon.pokemon = into;
+    pokemon.hp = pokemon.max_hp().saturating_sub(damage);
+}
@@ -0,0 +1,82 @@
+use pokedex::pokemon::{owned::OwnedPokemon, Experience, Level, Pokemon};
+
+pub const MAX_LEVEL: Level = 100;
+
+/// The experience gained between two (level, experience) pairs, where experience is progress into the level.
+pub fn gained(pokemon: &Pokemon, previous: (Level, Experience), current: (Level, Experience)) -> Experience {
+    let growth = &pokemon.training.growth_rate;
+    gained_with(|level| growth.max_exp(level), previous, current)
+}
+
+fn gained_with(max_exp: impl Fn(Level) -> Experience, previous: (Level, Experience), current: (Level, Experience)) -> Experience {
+    match current.0 > previous.0 {
+        true => {
+            let mut gained = max_exp(previous.0).saturating_sub(previous.1);
+            for level in previous.0 + 1..current.0 {
+                gained = gained.saturating_add(max_exp(level));
+            }
+            gained.saturating_add(current.1)
+        }
+        false => current.1.saturating_sub(previous.1),
+    }
+}
+
+/// Splits experience from a defeated pokemon, returning what each participant and each Exp. Share holder gains.
+/// Participants split all of it, or half of it when anyone holds an Exp. Share, and holders split the other half.
+pub fn share(experience: Experience, participants: usize, holders: usize) -> (Experience, Experience) {
+    let split = |experience: Experience, count: usize| match count {
+        0 => 0,
+        count => (experience / count as Experience).max(1),
+    };
+    match holders {
+        0 => (split(experience, participants), 0),
+        holders => (split(experience / 2, participants), split(experience / 2, holders)),
+    }
+}
+
+/// Adds experience to a pokemon, leveling it up without any animation. Returns the level it had before.
+pub fn add(pokemon: &mut OwnedPokemon, experience: Experience) -> Level {
+    let previous = pokemon.level;
+    pokemon.experience = pokemon.experience.saturating_add(experience);
+    while pokemon.level < MAX_LEVEL {
+        let max = pokemon.pokemon.training.growth_rate.max_exp(pokemon.level);
+        if pokemon.experience < max {
+            break;
+        }
+        pokemon.experience -= max;
+        pokemon.level += 1;
+    }
+    previous
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gained_within_level() {
+        assert_eq!(gained_with(|_| 100, (5, 20), (5, 70)), 50);
+    }
+
+    #[test]
+    fn gained_across_levels() {
+        // 80 to finish level 5, 100 for level 6 and 30 into level 7
+        assert_eq!(gained_with(|_| 100, (5, 20), (7, 30)), 210);
+    }
+
+    #[test]
+    fn share_between_participants() {
+        assert_eq!(share(300, 3, 0), (100, 0));
+    }
+
+    #[test]
+    fn share_with_holders() {
+        assert_eq!(share(300, 3, 2), (50, 75));
+    }
+
+    #[test]
+    fn share_gives_at_least_one() {
+        assert_eq!(share(1, 4, 1), (1, 1));
+        assert_eq!(share(100, 0, 0), (0, 0));
+    }
+}
@@ -8,27 +8,33 @@ use context::BattleGuiContext;
 use log::{warn, debug};
 use hashbrown::HashMap;
 
-use pokedex::{Dex, Identifiable, Initializable, Uninitializable, context::PokedexClientContext, gui::{bag::BagGui, party::PartyGui}, item::{Item, bag::Bag, usage::ItemUsageKind}, moves::{Move, MoveTarget}, pokemon::{Pokemon, owned::OwnedPokemon, party::Party}, types::Effective};
+use pokedex::{Dex, Identifiable, TrainerId, ailment::Ailment, Initializable, Uninitializable, context::PokedexClientContext, gui::{bag::BagGui, party::PartyGui}, item::{Item, ItemId, bag::Bag, usage::ItemUsageKind}, moves::{Move, MoveTarget}, pokemon::{Pokemon, owned::OwnedPokemon, party::Party}, types::Effective};
 
 use pokedex::engine::{
     graphics::ZERO, 
-    tetra::{Context, math::Vec2, graphics::Color}, 
+    input::down,
+    tetra::{Context, math::{Mat4, Vec2}, graphics::{Color, reset_transform_matrix, set_transform_matrix}},
+    text::MessagePage,
     util::{Entity, Completable, Reset},
     EngineContext,
 };
 
-use battle::{BattleData, BattleType, endpoint::{BattleEndpoint}, message::{ClientMessage, ServerMessage}, moves::{BattleMove, ClientMove, ClientMoveAction, damage::ClientDamage}, party::PlayerParty, pokemon::{Indexed, PokemonIdentifier, remote::RemotePokemon}, prelude::{FailedAction, StartableAction}, endpoint::{MpscClient, MpscEndpoint}};
+use battle::{BattleData, BattleType, endpoint::{BattleEndpoint}, message::{ClientMessage, ServerMessage}, moves::{BattleMove, ClientMove, ClientMoveAction, damage::ClientDamage}, party::PlayerParty, pokemon::{Indexed, PokemonIdentifier, PokemonView, remote::RemotePokemon}, prelude::{FailedAction, StartableAction}, endpoint::{MpscClient, MpscEndpoint}};
 use ui::view::ActivePlayer;
 use view::GuiPokemonView;
 
 use self::{
     ui::{
         BattleGui,
+        background::BattleEnvironment,
         panels::BattlePanels,
+        pokemon::PokemonStatusGui,
         view::{
             GuiLocalPlayer,
             GuiRemotePlayer,
             ActivePokemonRenderer,
+            DialogueTrigger,
+            TrainerPrize,
         },
     },
     view::PlayerView,
@@ -39,10 +45,20 @@ pub mod view;
 pub mod transition;
 pub mod ui;
 pub mod context;
+pub mod sound;
+pub mod options;
+pub mod catalog;
+pub mod exp;
+pub mod evolution;
+#[cfg(feature = "audio")]
+pub mod music;
 
 use action::*;
 
-use self::transition::TransitionState;
+use self::{catalog::BattleCatalog, evolution::{Evolutions, PendingEvolution}, options::BattleGuiOptions, sound::BattleSounds, transition::{TransitionState, trainer::PartyBall}};
+
+#[cfg(feature = "audio")]
+use self::{music::BattleMusic, sound::RepeatingSound};
 
 pub struct BattlePlayerGui<'d, ID: Default + Eq + Hash, const AS: usize> {
 
@@ -50,12 +66,23 @@ pub struct BattlePlayerGui<'d, ID: Default + Eq + Hash, const AS: usize> {
 
     party: Rc<PartyGui>,
     bag: Rc<BagGui>,
-	pub gui: BattleGui<'d>,
+	pub gui: BattleGui<'d, ID, AS>,
 
     state: BattlePlayerState<'d, ID>,
     should_select: bool,
+    ended: bool,
+    turn: u16,
+    /// Party indices of the player's pokemon that have fought each opposing active pokemon.
+    participants: Vec<(PokemonIdentifier<ID>, Vec<usize>)>,
+    /// Page of the introduction where the opposing trainer sends out their pokemon and slides away.
+    /// Kept in step with the pages by [`ui::text::layout`].
+    send_out: usize,
+    evolving: Vec<PendingEvolution>,
 
     pub data: BattleData,
+    /// Where the battle takes place, which picks its background, platforms, opener overlay and tint.
+    /// Set by the host before the battle starts.
+    pub environment: BattleEnvironment,
 
     pub local: GuiLocalPlayer<'d, ID, AS>,
     pub remotes: HashMap<ID, GuiRemotePlayer<'d, ID, AS>>,
@@ -67,6 +94,23 @@ pub struct BattlePlayerGui<'d, ID: Default + Eq + Hash, const AS: usize> {
     movedex: &'d dyn Dex<Move>,
     itemdex: &'d dyn Dex<Item>,
 
+    pub sounds: BattleSounds,
+    pub catalog: BattleCatalog,
+    pub options: BattleGuiOptions,
+    /// Multiplies the time passed to the battle, such as for replays.
+    speed: f32,
+    /// Held item that shares experience with a pokemon that did not battle, if there is one.
+    pub exp_share: Option<ItemId>,
+    pub evolutions: Evolutions,
+    /// If the battle runs its own evolution scene instead of leaving evolutions to the host.
+    pub evolve: bool,
+    #[cfg(feature = "audio")]
+    alarm: RepeatingSound,
+    #[cfg(feature = "audio")]
+    exp_tick: RepeatingSound,
+    #[cfg(feature = "audio")]
+    pub music: BattleMusic,
+
 }
 
 #[derive(Debug)]
@@ -79,6 +123,8 @@ struct MoveQueue<'d, ID> {
 #[derive(Debug)]
 enum BattlePlayerState<'d, ID> {
     WaitToStart,
+    /// Flashes and wipes over the host's last frame.
+    Entry(TransitionState),
     Opening(TransitionState),
     Introduction(TransitionState),
     WaitToSelect,
@@ -89,6 +135,13 @@ enum BattlePlayerState<'d, ID> {
 
 impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerGui<'d, ID, AS> {
 
+    /// Slowest and fastest speeds the battle can play at, including fast forward.
+    const MIN_SPEED: f32 = 0.25;
+    const MAX_SPEED: f32 = 8.0;
+
+    /// Id of the item given to [`exp_share`](Self::exp_share) by default.
+    const EXP_SHARE: &'static str = "exp_share";
+
     pub fn new(ctx: &mut Context, dex: &PokedexClientContext<'d>, party: Rc<PartyGui>, bag: Rc<BagGui>) -> Self where ID: Default {
         let context = BattleGuiContext::new(ctx);
 
@@ -100,7 +153,13 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
 			gui: BattleGui::new(ctx, &context),
             state: BattlePlayerState::WaitToStart,
             should_select: false,
+            ended: false,
+            turn: 0,
+            participants: Vec::new(),
+            send_out: 0,
+            evolving: Vec::new(),
             data: Default::default(),
+            environment: Default::default(),
             local: ActivePlayer::new(PlayerParty::new(Default::default(), None, Default::default())),
             remotes: Default::default(),
             client,
@@ -109,9 +168,34 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
             pokedex: dex.pokedex,
             movedex: dex.movedex,
             itemdex: dex.itemdex,
+            sounds: Default::default(),
+            catalog: Default::default(),
+            options: Default::default(),
+            speed: 1.0,
+            exp_share: match Self::EXP_SHARE.parse() {
+                Ok(id) => Some(id),
+                Err(..) => {
+                    warn!("Could not parse exp share item id {}", Self::EXP_SHARE);
+                    None
+                }
+            },
+            evolutions: Default::default(),
+            evolve: false,
+            #[cfg(feature = "audio")]
+            alarm: RepeatingSound::new(0.5),
+            #[cfg(feature = "audio")]
+            exp_tick: RepeatingSound::new(0.1),
+            #[cfg(feature = "audio")]
+            music: Default::default(),
         }
     }
 
+    /// Plays the battle with the given options instead of the defaults.
+    pub fn with_options(mut self, options: BattleGuiOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     pub fn endpoint(&self) -> MpscEndpoint<ID, AS> {
         self.endpoint.clone()
     }
@@ -124,15 +208,61 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
         }
     }
 
+    /// If the battle has a winner and the trainer outro and evolutions are done.
+    pub fn finished(&self) -> bool {
+        matches!(self.state, BattlePlayerState::Winner(..)) && !self.gui.outro.alive() && !self.gui.evolution.alive() && !(self.evolve && !self.evolving.is_empty())
+    }
+
+    /// Party pokemon that can evolve after the battle, for hosts that run their own evolution scene.
+    /// Read these before calling [`end`](Self::end); their indices are into the party it returns.
+    pub fn evolutions(&self) -> &[PendingEvolution] {
+        &self.evolving
+    }
+
+    /// The prize given by a defeated trainer, once the battle has finished.
+    pub fn prize(&self) -> Option<&TrainerPrize> {
+        match self.finished() {
+            true => self.gui.outro.prize(),
+            false => None,
+        }
+    }
+
     pub fn battling(&self) -> bool {
-        !matches!(self.state, BattlePlayerState::WaitToStart | BattlePlayerState::Opening(..) | BattlePlayerState::Introduction(..))
+        !matches!(self.state, BattlePlayerState::WaitToStart | BattlePlayerState::Entry(..) | BattlePlayerState::Opening(..) | BattlePlayerState::Introduction(..))
     }
 
+    /// If the battle is drawing its entry transition over the host's last frame.
+    /// The host should keep drawing its scene before the battle while this is true.
+    pub fn transitioning(&self) -> bool {
+        matches!(self.state, BattlePlayerState::Entry(..))
+    }
+
+    /// Starts the battle in its [`environment`](Self::environment).
     pub fn start(&mut self, transition: bool) {
+        self.gui.background.set(self.environment);
         self.state = match transition {
-            true => BattlePlayerState::Opening(TransitionState::default()),
+            true => BattlePlayerState::Entry(TransitionState::default()),
             false => BattlePlayerState::WaitToSelect,
         };
+        self.ended = false;
+        self.turn = 0;
+    }
+
+    /// Stops the battle, gives the music back to the host and hands back the player's party,
+    /// with the experience, levels, moves and evolutions it gained. The host should replace its party with it.
+    #[must_use = "the party holds the experience and evolutions gained during the battle"]
+    #[cfg_attr(not(feature = "audio"), allow(unused_variables))]
+    pub fn end(&mut self, ctx: &EngineContext) -> Party<OwnedPokemon<'d>> {
+        self.state = BattlePlayerState::WaitToStart;
+        self.ended = false;
+        self.evolving.clear();
+        self.gui.outro.reset();
+        #[cfg(feature = "audio")] {
+            self.alarm.stop();
+            self.exp_tick.stop();
+            self.music.end(ctx);
+        }
+        std::mem::take(&mut self.local.player.pokemon)
     }
 
     pub fn process(&mut self, random: &mut impl rand::Rng, dex: &PokedexClientContext, party: &mut Party<OwnedPokemon<'d>>) {
@@ -154,6 +284,8 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                         }))
                     }).collect();
                     self.data = data.data;
+                    self.participants.clear();
+                    self.evolving.clear();
                     self.local.init(&self.context, dex);
                     for remote in self.remotes.values_mut() {
                         remote.init(&self.context, dex);
@@ -165,9 +297,11 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                         self.gui.panel.despawn();
                     },
                     StartableAction::Turns(queue) => {
+                        self.turn = self.turn.saturating_add(1);
+                        let actions = queue.into_iter().map(|a| Indexed(a.0, BattleClientGuiAction::Action(a.1)),
+                            ).collect();
                         self.state = BattlePlayerState::Moving(MoveQueue {
-                            actions: queue.into_iter().map(|a| Indexed(a.0, BattleClientGuiAction::Action(a.1)),
-                            ).collect(),
+                            actions,
                             current: None,
                         });
                         self.gui.text.clear();
@@ -227,7 +361,10 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                     None => warn!("Could not initialize caught pokemon.")
                 }
                 ServerMessage::End => {
-
+                    self.ended = true;
+                    if !matches!(self.state, BattlePlayerState::Moving(..)) {
+                        self.win(dex);
+                    }
                 },
                 // ServerMessage::AddMove(pokemon, index, move_ref) => if pokemon.team == self.local.party.id {
                 //     if let Some(pokemon) = self.local.party.pokemon.get_mut(pokemon.index) {
@@ -241,32 +378,202 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
         }
     }
 
+    /// Takes the dialogue of the first trainer whose trigger has been met.
+    fn trainer_dialogue(remotes: &mut HashMap<ID, GuiRemotePlayer<'d, ID, AS>>, turn: u16) -> Option<(PokemonIdentifier<ID>, Option<TrainerId>, Vec<MessagePage>)> {
+        for remote in remotes.values_mut() {
+            if remote.dialogue.during.is_empty() {
+                continue;
+            }
+            let remaining = remote.player.pokemon.iter().filter(|pokemon| !pokemon.fainted()).count();
+            let fainted = remaining < remote.player.pokemon.len();
+            let last = remaining == 1 && remote.player.active_iter().any(|(.., pokemon)| !pokemon.fainted());
+            let low = remote.player.active_iter().any(|(.., pokemon)| pokemon.hp() > 0.0 && pokemon.hp() < PokemonStatusGui::RED);
+            let triggered = remote.dialogue.during.iter().position(|(trigger, ..)| match trigger {
+                DialogueTrigger::LastPokemon => last,
+                DialogueTrigger::FirstFaint => fainted,
+                DialogueTrigger::LowHealth => low,
+                DialogueTrigger::Turn(n) => *n == turn,
+            });
+            if let Some(index) = triggered {
+                let (.., pages) = remote.dialogue.during.remove(index);
+                return Some((PokemonIdentifier(remote.player.id.clone(), 0), remote.trainer.clone(), pages));
+            }
+        }
+        None
+    }
+
+    /// Ends the battle, starting the trainer outro if the player beat a trainer.
+    fn win(&mut self, dex: &PokedexClientContext) {
+        let winner = self.winner_id();
+        self.gui.outro.reset();
+        #[cfg(feature = "audio")]
+        self.alarm.stop();
+        if winner.as_ref() == Some(self.local.player.id()) && !matches!(self.data.type_, BattleType::Wild) {
+            if let Some(remote) = self.remotes.values().next() {
+                let unknown = self.catalog.get("name.unknown", &[]);
+                let player = PlayerView::name(&self.local.player).unwrap_or(&unknown);
+                self.gui.outro.begin(dex, &self.catalog, player, remote, &mut self.gui.text);
+            }
+        }
+        if winner.as_ref() == Some(self.local.player.id()) {
+            #[cfg(feature = "audio")]
+            self.music.victory();
+        } else {
+            self.evolving.clear();
+        }
+        self.state = BattlePlayerState::Winner(winner);
+    }
+
+    /// The player whose opponents have no pokemon left.
+    fn winner_id(&self) -> Option<ID> {
+        if self.remotes.values().all(|remote| remote.player.pokemon.iter().all(|p| p.fainted())) {
+            Some(self.local.player.id().clone())
+        } else if self.local.player.pokemon.iter().all(|p| p.fainted()) {
+            self.remotes.keys().next().cloned()
+        } else {
+            None
+        }
+    }
+
+    /// If an opposing trainer is down to their last pokemon.
+    fn last_pokemon(&self) -> bool {
+        !matches!(self.data.type_, BattleType::Wild) && self.remotes.values().any(|remote| remote.player.pokemon.iter().filter(|p| !p.fainted()).count() == 1)
+    }
+
+    /// If the opponent's strongest pokemon is a higher level than the player's.
+    fn stronger_opponent(local: &GuiLocalPlayer<'d, ID, AS>, remotes: &HashMap<ID, GuiRemotePlayer<'d, ID, AS>>) -> bool {
+        let opponent = remotes.values().flat_map(|remote| remote.player.pokemon.iter()).map(|pokemon| pokemon.level()).max();
+        let player = local.player.pokemon.iter().map(|pokemon| pokemon.level).max();
+        opponent > player
+    }
+
+    /// Center of the sprite of an active pokemon.
+    fn position(local: &GuiLocalPlayer<'d, ID, AS>, remotes: &HashMap<ID, GuiRemotePlayer<'d, ID, AS>>, id: &PokemonIdentifier<ID>) -> Option<Vec2<f32>> {
+        match id.team() == local.player.id() {
+            true => local.renderer.get(id.index()),
+            false => remotes.get(id.team()).map(|remote| remote.renderer.get(id.index())).flatten(),
+        }.map(|active| active.pokemon.pos + Vec2::new(32.0, -32.0))
+    }
+
+    /// How many times faster the battle plays, not counting the fast forward button.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets how many times faster the battle plays, clamped to a usable range.
+    /// Speeds that are not finite are ignored.
+    pub fn set_speed(&mut self, speed: f32) {
+        match speed.is_finite() {
+            true => self.speed = Self::clamp_speed(speed),
+            false => warn!("Ignoring battle speed {}", speed),
+        }
+    }
+
+    fn clamp_speed(speed: f32) -> f32 {
+        speed.max(Self::MIN_SPEED).min(Self::MAX_SPEED)
+    }
+
     pub fn update(&mut self, ctx: &EngineContext, dex: &PokedexClientContext, delta: f32, bag: &mut Bag<'d>) {
+        let mut speed = self.speed;
+        if let Some(control) = self.options.fast_forward {
+            let fast_forward = self.options.fast_forward_speed;
+            if down(ctx, control) && fast_forward.is_finite() {
+                speed = Self::clamp_speed(speed * fast_forward);
+            }
+        }
+        self.update_battle(ctx, dex, delta * speed, bag);
+    }
+
+    fn update_battle(&mut self, ctx: &EngineContext, dex: &PokedexClientContext, delta: f32, bag: &mut Bag<'d>) {
+        ui::text::layout(ctx, &mut self.gui.text, &mut self.send_out);
+        self.local.renderer.iter_mut()
+            .chain(self.remotes.values_mut().flat_map(|remote| remote.renderer.iter_mut()))
+            .for_each(|active| active.update_ailment(delta));
+        #[cfg(feature = "audio")] {
+            let battling = self.battling() && !matches!(self.state, BattlePlayerState::Winner(..));
+            let low = battling && self.local.renderer.iter().any(|active| active.status.low_health());
+            // stops on a switch, faint, heal or when the battle is won
+            self.alarm.set(low);
+            self.alarm.update(ctx, delta, &self.sounds.low_health);
+            // starts the music on whichever path the battle was entered from
+            if battling && !self.music.started() {
+                self.music.begin(ctx, self.data.type_);
+            }
+            self.music.update(ctx, delta, low, battling && self.last_pokemon());
+        }
         match &mut self.state {
-            BattlePlayerState::WaitToStart | BattlePlayerState::Winner(..) => (),
+            BattlePlayerState::WaitToStart => (),
+            BattlePlayerState::Winner(..) => {
+                self.gui.outro.update(ctx, delta, &self.options, &mut self.gui.text);
+                if self.evolve && !self.gui.outro.alive() {
+                    match self.gui.evolution.alive() {
+                        true => if let Some(evolved) = self.gui.evolution.update(ctx, delta, &self.options, &self.catalog, &mut self.gui.text) {
+                            let evolution = self.evolving.remove(0);
+                            if evolved {
+                                if let (Some(pokemon), Some(into)) = (self.local.player.pokemon.get_mut(evolution.index), self.pokedex.try_get(&evolution.into)) {
+                                    evolution::evolve(pokemon, into);
+                                }
+                            }
+                        },
+                        false => if let Some(evolution) = self.evolving.first() {
+                            match (self.local.player.pokemon.get(evolution.index).filter(|pokemon| !pokemon.fainted()), self.pokedex.try_get(&evolution.into)) {
+                                (Some(pokemon), Some(into)) => self.gui.evolution.spawn(dex, &self.catalog, pokemon, into, &mut self.gui.text),
+                                (pokemon, ..) => {
+                                    if pokemon.is_some() {
+                                        warn!("Could not find pokemon #{} to evolve into", evolution.into);
+                                    }
+                                    self.evolving.remove(0);
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+            BattlePlayerState::Entry(state) => match state {
+                TransitionState::Begin => {
+                    let stronger = Self::stronger_opponent(&self.local, &self.remotes);
+                    self.gui.entry.begin(state, self.data.type_, stronger, &self.options);
+                    self.update_battle(ctx, dex, delta, bag);
+                }
+                TransitionState::Run => self.gui.entry.update(state, delta),
+                TransitionState::End => {
+                    self.state = BattlePlayerState::Opening(TransitionState::default());
+                    self.update_battle(ctx, dex, delta, bag);
+                }
+            }
             BattlePlayerState::Opening(state) => match state {
                 TransitionState::Begin => {
-                    self.gui.opener.begin(dex, state, self.data.type_, &self.remotes.values().next().unwrap());
+                    #[cfg(feature = "audio")]
+                    self.music.begin(ctx, self.data.type_);
+                    self.gui.opener.begin(dex, state, &self.data, &self.gui.background, &self.remotes.values().next().unwrap());
                     if !matches!(self.data.type_, BattleType::Wild) {
-                        self.gui.trainer.spawn(self.local.player.pokemon.len(), self.remotes.values().next().unwrap().player.pokemon.len());
+                        self.gui.trainer.spawn(
+                            self.local.player.pokemon.iter().map(PartyBall::of),
+                            self.remotes.values().next().unwrap().player.pokemon.iter().map(PartyBall::of),
+                        );
                     }
-                    self.update(ctx, dex, delta, bag);
+                    self.update_battle(ctx, dex, delta, bag);
                 }
-                TransitionState::Run => self.gui.opener.update::<ID, AS>(state, delta),
+                TransitionState::Run => self.gui.opener.update(state, delta),
                 TransitionState::End => {
                     self.state = BattlePlayerState::Introduction(TransitionState::default());
-                    self.update(ctx, dex, delta, bag);
+                    self.update_battle(ctx, dex, delta, bag);
                 }
             }
             BattlePlayerState::Introduction(state) => match state {
                 TransitionState::Begin => {
-                    self.gui.introduction.begin(dex, state, self.data.type_, &self.local, &self.remotes.values().next().unwrap(), &mut self.gui.text);
-                    self.update(ctx, dex, delta, bag);
+                    self.gui.introduction.begin(dex, &self.catalog, state, &self.data, &self.local, &self.remotes.values().next().unwrap(), &mut self.gui.text);
+                    // the send out comes after a gym leader's dialogue and the challenge
+                    self.send_out = match self.data.type_ {
+                        BattleType::GymLeader => self.remotes.values().next().map(|remote| remote.dialogue.before.len()).unwrap_or_default(),
+                        _ => 0,
+                    } + 1;
+                    self.update_battle(ctx, dex, delta, bag);
                 }
                 TransitionState::Run => {
-                    self.gui.introduction.update(state, ctx, delta, &mut self.local, &mut self.remotes.values_mut().next().unwrap(), &mut self.gui.text);
+                    self.gui.introduction.update(state, ctx, delta, &self.options, &mut self.local, &mut self.remotes.values_mut().next().unwrap(), &mut self.gui.text);
                     self.gui.trainer.update(delta);
-                    if self.gui.text.page() > 0 && !self.gui.trainer.ending() && !matches!(self.data.type_, BattleType::Wild) {
+                    if self.gui.text.page() >= self.send_out && !self.gui.trainer.ending() && !matches!(self.data.type_, BattleType::Wild) {
                         self.gui.trainer.end();
                     }
                 }
@@ -274,7 +581,7 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                     self.gui.introduction.end(&mut self.gui.text);
                     self.gui.trainer.despawn();
                     self.state = BattlePlayerState::WaitToSelect;
-                    self.update(ctx, dex, delta, bag);
+                    self.update_battle(ctx, dex, delta, bag);
                 }
             }
             BattlePlayerState::WaitToSelect => if self.should_select {
@@ -282,7 +589,9 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                 self.state = BattlePlayerState::Select(0);
             }
             BattlePlayerState::Select(active_index) => {
-                self.gui.bounce.update(delta);
+                if self.options.animations {
+                    self.gui.bounce.update(delta);
+                }
                 match self.local.player.active.get(*active_index) {
                     Some(index) => match index {
                         Some(index) => {
@@ -319,7 +628,7 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                             *active_index += 1;
                                             self.gui.panel.despawn();
                                         }
-                                    } else if let Some(panels) = self.gui.panel.input(ctx, pokemon) {
+                                    } else if let Some(panels) = self.gui.panel.input(ctx, &self.catalog, pokemon) {
                                         match panels {
                                             BattlePanels::Main => {
                                                 match self.gui.panel.battle.cursor {
@@ -327,6 +636,7 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                                     1 => self.bag.spawn(),
                                                     2 => self.party.spawn(dex, &self.local.player.pokemon, Some(false), true),
                                                     3 => if matches!(self.data.type_, BattleType::Wild) {
+                                                        sound::play(ctx, &self.sounds.run);
                                                         self.client.send(ClientMessage::Forfeit);
                                                     },
                                                     _ => unreachable!(),
@@ -337,11 +647,11 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                                     Some(move_ref) => {
                                                         match move_ref.target {
                                                             MoveTarget::Opponent | MoveTarget::Any => {
-                                                                self.gui.panel.target(&self.remotes.values().next().unwrap().player);
+                                                                self.gui.panel.target(&self.catalog, &self.remotes.values().next().unwrap().player);
                                                                 self.gui.panel.active = BattlePanels::Target(move_ref.target, None);
                                                             },
                                                             MoveTarget::Ally | MoveTarget::UserOrAlly => {
-                                                                self.gui.panel.target(&self.local.player);
+                                                                self.gui.panel.target(&self.catalog, &self.local.player);
                                                                 self.gui.panel.active = BattlePanels::Target(move_ref.target, None);
                                                             }
                                                             _ => {
@@ -363,6 +673,22 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                                 }
                                                 None => warn!("Could not get move at cursor!"),
                                             }
+                                            BattlePanels::Stages => {
+                                                let stages = &mut self.gui.panel.stages;
+                                                stages.clear();
+                                                for (index, active) in self.local.renderer.iter().enumerate() {
+                                                    if let Some(pokemon) = self.local.player.active(index) {
+                                                        stages.add(&self.catalog, pokemon.name(), &active.pokemon.stages);
+                                                    }
+                                                }
+                                                for remote in self.remotes.values() {
+                                                    for (index, active) in remote.renderer.iter().enumerate() {
+                                                        if let Some(pokemon) = remote.player.active(index) {
+                                                            stages.add(&self.catalog, pokemon.name(), &active.pokemon.stages);
+                                                        }
+                                                    }
+                                                }
+                                            }
                                             BattlePanels::Target(target, item) => {
                                                 self.client.send(
                                                     ClientMessage::Move(
@@ -391,7 +717,12 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                     }
                                 }
                                 false => {
-                                    self.gui.panel.user(pokemon);
+                                    let targets = self.remotes.values().next().map(|remote| {
+                                        (0..remote.player.active.len())
+                                            .map(|index| remote.player.active(index).map(|p| p.as_ref().map(|p| p.pokemon)).flatten())
+                                            .collect()
+                                    }).unwrap_or_default();
+                                    self.gui.panel.user(&self.catalog, pokemon, targets);
                                     self.gui.panel.spawn();
                                 }
                             }
@@ -405,16 +736,57 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
             },
             BattlePlayerState::Moving(queue) => {
 
+                self.gui.trainer.update(delta);
+
+                for remote in self.remotes.values() {
+                    for (index, ..) in remote.player.active_iter() {
+                        let foe = PokemonIdentifier(remote.player.id.clone(), index);
+                        let fought = match self.participants.iter().position(|(id, ..)| id == &foe) {
+                            Some(position) => &mut self.participants[position].1,
+                            None => {
+                                self.participants.push((foe, Vec::new()));
+                                &mut self.participants.last_mut().unwrap().1
+                            }
+                        };
+                        for index in self.local.player.active.iter().flatten() {
+                            if !fought.contains(index) {
+                                fought.push(*index);
+                            }
+                        }
+                    }
+                }
+
+                if queue.current.is_none() && !matches!(self.data.type_, BattleType::Wild) {
+                    if let Some((id, trainer, pages)) = Self::trainer_dialogue(&mut self.remotes, self.turn) {
+                        self.gui.text.clear();
+                        self.gui.text.reset();
+                        for page in pages {
+                            self.gui.text.push(page);
+                        }
+                        self.gui.dialogue.spawn(dex, trainer.as_ref());
+                        queue.current = Some(Indexed(id, BattleClientGuiCurrent::Dialogue));
+                    }
+                }
+
                 match &mut queue.current {
                     None => {
                         match queue.actions.pop_front() {
                             None => {
                                 // self.messages.send(ClientMessage::FinishedTurnQueue);
-                                self.state = BattlePlayerState::WaitToSelect;
+                                match self.ended {
+                                    true => self.win(dex),
+                                    false => self.state = BattlePlayerState::WaitToSelect,
+                                }
                             }
                             Some(Indexed(user_id, action)) => {
 
-                                if let Some((user, user_ui)) = match user_id.team() == self.local.player.id() {
+                                let local = user_id.team() == self.local.player.id();
+                                let party_index = match local {
+                                    true => self.local.player.active.get(user_id.index()).copied().flatten(),
+                                    false => None,
+                                };
+
+                                if let Some((user, user_ui)) = match local {
                                     true => Some((&mut self.local.player as &mut dyn PlayerView<'d, ID, AS>, &mut self.local.renderer)),
                                     false => self.remotes.get_mut(user_id.team()).map(|p| (&mut p.player as _, &mut p.renderer))
                                 } {
@@ -437,15 +809,26 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
 
                                                             let user_active = user.active_mut(user_id.index()).unwrap();
             
-                                                            ui::text::on_move(&mut self.gui.text, &pokemon_move, user_active.name());
+                                                            ui::text::on_move(&mut self.gui.text, &self.catalog, &pokemon_move, user_active.name());
 
                                                             user_active.decrement_pp(pp);
 
+                                                            if let (true, Some(lunge)) = (self.options.animations, self.gui.moves.lunge(&pokemon_move.id)) {
+                                                                user_ui[user_id.index()].pokemon.moves.init(lunge);
+                                                            }
+
                                                         }
 
                                                         drop(user);
                                                         drop(user_ui);
 
+                                                        if let (true, Some(animation)) = (self.options.animations, self.gui.moves.animation(&pokemon_move.id)) {
+                                                            if let Some(user_pos) = Self::position(&self.local, &self.remotes, &user_id) {
+                                                                let target_pos = targets.first().map(|Indexed(target_id, ..)| Self::position(&self.local, &self.remotes, target_id)).flatten();
+                                                                self.gui.animation.play(animation, user_pos, target_pos.unwrap_or(user_pos), user_id.team() != self.local.player.id());
+                                                            }
+                                                        }
+
                                                         let mut faint = Vec::new();
             
                                                         for Indexed(target_id, action) in &targets {
@@ -463,36 +846,64 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                                             if let Some((target, target_ui)) = target {
                                                                     match *action {
                                                                         ClientMoveAction::SetHP(result) => {
+                                                                            let previous = target.hp();
                                                                             target.set_hp(result.damage());
-                                                                            if let ClientDamage::Result(result) = result {
-                                                                                match result.damage > 0.0 {
-                                                                                    true => target_ui.pokemon.flicker(),
-                                                                                    false => faint.push(target_id),
-                                                                                }
-                                                                                if result.effective != Effective::Effective {
-                                                                                    ui::text::on_effective(&mut self.gui.text, &result.effective)
+                                                                            match result {
+                                                                                ClientDamage::Result(result) => {
+                                                                                    match result.damage > 0.0 {
+                                                                                        true => target_ui.pokemon.flicker(),
+                                                                                        false => faint.push(target_id),
+                                                                                    }
+                                                                                    if result.effective != Effective::Effective {
+                                                                                        ui::text::on_effective(&mut self.gui.text, &self.catalog, &result.effective)
+                                                                                    }
+                                                                                    if result.crit {
+                                                                                        ui::text::on_crit(&mut self.gui.text, &self.catalog);
+                                                                                    }
                                                                                 }
-                                                                                if result.crit {
-                                                                                    ui::text::on_crit(&mut self.gui.text);
+                                                                                // health the server took outside of a hit, such as from poison or a burn
+                                                                                _ => {
+                                                                                    if target.hp() < previous {
+                                                                                        if let Some(ailment) = target.ailment().map(|a| a.ailment).filter(|a| matches!(a, Ailment::Poison | Ailment::Burn)) {
+                                                                                            ui::text::on_ailment_hurt(&mut self.gui.text, &self.catalog, target.name(), ailment);
+                                                                                        }
+                                                                                        target_ui.pokemon.flicker();
+                                                                                    }
+                                                                                    if target.hp() <= 0.0 {
+                                                                                        faint.push(target_id);
+                                                                                    }
                                                                                 }
                                                                             }
                                                                         },
-                                                                        ClientMoveAction::Error => ui::text::on_fail(&mut self.gui.text, vec![format!("{} cannot use move", target.name()), format!("{}, as there was an error.", pokemon_move.name)]),
-                                                                        ClientMoveAction::Miss => ui::text::on_miss(&mut self.gui.text, target.name()),
+                                                                        ClientMoveAction::Error => ui::text::on_fail(&mut self.gui.text, self.catalog.get("move.error", &[("pokemon", &target.name()), ("move", &pokemon_move.name)])),
+                                                                        ClientMoveAction::Miss => ui::text::on_miss(&mut self.gui.text, &self.catalog, target.name()),
                                                                         ClientMoveAction::SetExp(experience, level) => {
                                                                             let previous = target.level();
+                                                                            let gained = exp::gained(target.pokemon(), (previous, target.exp()), (level, experience));
                                                                             target.set_level(level);
                                                                             target.set_exp(experience);
                                                                             if let Some(user_pokemon) = target.instance() {
                                                                                 let movedex = self.movedex;
                                                                                 let moves = user_pokemon.on_level_up(previous).flat_map(|id| movedex.try_get(&id)).collect();
-                                                                                queue.actions.push_front(Indexed(target_id.clone(), BattleClientGuiAction::SetExp(previous, experience, moves)));
+                                                                                let foe = faint.last().map(|foe| (*foe).clone());
+                                                                                queue.actions.push_front(Indexed(target_id.clone(), BattleClientGuiAction::SetExp(previous, gained, moves, foe)));
                                                                             }
                                                                         }
-                                                                        ClientMoveAction::AddStat(stat, stage) => ui::text::on_stat_stage(&mut self.gui.text, target.name(), stat, stage),
+                                                                        ClientMoveAction::AddStat(stat, stage) => {
+                                                                            target_ui.pokemon.stages.add(stat, stage);
+                                                                            sound::play(ctx, match stage.is_positive() {
+                                                                                true => &self.sounds.stat_rise,
+                                                                                false => &self.sounds.stat_fall,
+                                                                            });
+                                                                            ui::text::on_stat_stage(&mut self.gui.text, &self.catalog, target.name(), stat, stage)
+                                                                        }
                                                                         ClientMoveAction::Ailment(ailment) => {
+                                                                            // the server's ailment replaces the one the pokemon had, which has ended
+                                                                            if let Some(previous) = target.ailment().map(|a| a.ailment).filter(|previous| *previous != ailment.ailment) {
+                                                                                ui::text::on_ailment_cure(&mut self.gui.text, &self.catalog, target.name(), previous);
+                                                                            }
                                                                             target.set_ailment(ailment);
-                                                                            ui::text::on_status(&mut self.gui.text, target.name(), ailment.ailment);
+                                                                            ui::text::on_status(&mut self.gui.text, &self.catalog, target.name(), ailment.ailment);
                                                                         }
                                                                     }
         
@@ -525,10 +936,11 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                                         ItemUsageKind::None => None,
                                                     } {
                                                         if let ItemUsageKind::Pokeball = &item.usage.kind {
+                                                            sound::play(ctx, &self.sounds.ball_throw);
                                                             // self.messages.push(ClientMessage::RequestPokemon(index));
                                                             queue.actions.push_front(Indexed(target.clone(), BattleClientGuiAction::Catch));
                                                         }
-                                                        ui::text::on_item(&mut self.gui.text, pokemon.name(), &item);
+                                                        ui::text::on_item(&mut self.gui.text, &self.catalog, pokemon.name(), &item);
                                                     }
                                                     Some(BattleClientGuiCurrent::UseItem(target))
                                                 } else {
@@ -536,24 +948,32 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                                 }
                                             }
                                             ClientMove::Switch(index) => {
-                                                let coming = user.pokemon(index).map(|v| v.name()).unwrap_or("Unknown");
-                                                ui::text::on_switch(&mut self.gui.text, user.active(user_id.index()).map(|v| v.name()).unwrap_or("Unknown"), coming);
+                                                let unknown = self.catalog.get("name.unknown", &[]);
+                                                let coming = user.pokemon(index).filter(|v| v.revealed()).map(|v| v.name()).unwrap_or(&unknown);
+                                                let leaving = user.active(user_id.index()).filter(|v| v.revealed()).map(|v| v.name()).unwrap_or(&unknown);
+                                                ui::text::on_switch(&mut self.gui.text, &self.catalog, leaving, coming);
                                                 Some(BattleClientGuiCurrent::Switch(index))
                                             }
                                         }
                                         BattleClientGuiAction::Faint => {
+                                            sound::play(ctx, &self.sounds.faint);
                                             let is_player = user_id.team() == user.id();
                                             let target = user.active_mut(user_id.index()).unwrap();
                                             target.set_hp(0.0);
-                                            ui::text::on_faint(&mut self.gui.text, matches!(self.data.type_, BattleType::Wild), is_player, target.name());
-                                            user_ui[user_id.index()].pokemon.faint();
+                                            ui::text::on_faint(&mut self.gui.text, &self.catalog, matches!(self.data.type_, BattleType::Wild), is_player, target.name());
+                                            let renderer = &mut user_ui[user_id.index()].pokemon;
+                                            renderer.faint();
+                                            if !self.options.animations {
+                                                renderer.faint.remaining = 0.0;
+                                            }
                                             Some(BattleClientGuiCurrent::Faint)
                                         },
                                         BattleClientGuiAction::Catch => {
+                                            sound::play(ctx, &self.sounds.ball_wobble);
                                             match self.remotes.get_mut(user_id.team()) {
                                                 Some(remote) => {
                                                     if let Some(pokemon) = remote.player.active(user_id.index()) {
-                                                        ui::text::on_catch(&mut self.gui.text, pokemon.name());
+                                                        ui::text::on_catch(&mut self.gui.text, &self.catalog, pokemon.name());
                                                     }
                                                     // if let Some(pokemon) = pokemon {
                                                     remote.player.replace(user_id.index(), None);
@@ -568,35 +988,129 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                             
                                         }
                                         BattleClientGuiAction::Replace(new) => {
-                                            ui::text::on_replace(&mut self.gui.text, user.name(), new.map(|index| user.pokemon(index).map(|v| v.name())).flatten());
+                                            let unknown = self.catalog.get("name.unknown", &[]);
+                                            let coming = new.map(|index| user.pokemon(index)).flatten().map(|v| match v.revealed() {
+                                                true => v.name(),
+                                                false => unknown.as_str(),
+                                            });
+                                            ui::text::on_replace(&mut self.gui.text, &self.catalog, user.name().unwrap_or(&unknown), coming);
                                             user.replace(user_id.index(), new);
+                                            if new.is_some() && !local {
+                                                // a new opponent, so only pokemon that fight it share its experience
+                                                self.participants.retain(|(id, ..)| id != &user_id);
+                                            }
+                                            if new.is_some() && !local && !matches!(self.data.type_, BattleType::Wild) {
+                                                let mut balls = Vec::new();
+                                                while let Some(pokemon) = user.pokemon(balls.len()) {
+                                                    balls.push(PartyBall::of(pokemon));
+                                                }
+                                                self.gui.trainer.spawn_opponent(balls);
+                                            }
                                             Some(BattleClientGuiCurrent::Replace(false))
                                         }
-                                        // To - do: experience spreading
-                                        BattleClientGuiAction::SetExp(previous, experience, moves) => match user.active_mut(user_id.index()) {
+                                        BattleClientGuiAction::SetExp(previous, experience, moves, foe) => match user.active_mut(user_id.index()) {
                                             Some(pokemon) => {    
-                                                ui::text::on_gain_exp(&mut self.gui.text, pokemon.name(), experience, pokemon.level());
+                                                ui::text::on_gain_exp(&mut self.gui.text, &self.catalog, pokemon.name(), experience);
+                                                let level = pokemon.level();
                                                 let status = &mut user_ui[user_id.index()].status;
                                                 match pokemon.instance() {
-                                                    Some(p) => status.update_gui(Some(p), Some(previous), false),
+                                                    Some(p) => {
+                                                        status.update_gui(Some(p), Some(previous), false);
+                                                        if let Some(index) = party_index {
+                                                            evolution::check(&self.evolutions, &mut self.evolving, index, p);
+                                                        }
+                                                    }
                                                     None => status.update_gui_view(Some(pokemon), Some(previous), false),
                                                 }
-                                                queue.actions.push_front(Indexed(user_id.clone(), BattleClientGuiAction::LevelUp(moves)));
+                                                if local {
+                                                    queue.actions.push_front(Indexed(user_id.clone(), BattleClientGuiAction::ShareExp(experience, foe)));
+                                                }
+                                                if let Some(index) = party_index {
+                                                    queue.actions.push_front(Indexed(user_id.clone(), BattleClientGuiAction::LevelUp(index, moves, true)));
+                                                    for level in (previous + 1..=level).rev() {
+                                                        queue.actions.push_front(Indexed(user_id.clone(), BattleClientGuiAction::LevelStats(index, level)));
+                                                    }
+                                                }
                                                 Some(BattleClientGuiCurrent::SetExp)
                                             }
                                             None => None,
                                         }
-                                        BattleClientGuiAction::LevelUp(moves) => match user.active_mut(user_id.index()).map(|v| v.instance()).flatten() {
-                                            Some(instance) => {
-                                                match moves.is_empty() {
-                                                    false => {
-                                                        self.gui.level_up.spawn(instance, &mut self.gui.text, moves);
-                                                        Some(BattleClientGuiCurrent::LevelUp)
+                                        BattleClientGuiAction::ShareExp(experience, foe) => {
+                                            drop(user);
+                                            drop(user_ui);
+                                            // The server only gives experience to the active pokemon, which keeps its full share.
+                                            let fought = foe.as_ref()
+                                                .map(|foe| self.participants.iter().find(|(id, ..)| id == foe))
+                                                .flatten()
+                                                .map(|(.., fought)| fought.as_slice())
+                                                .unwrap_or_default();
+                                            let party = &self.local.player.pokemon;
+                                            let active = &self.local.player.active;
+                                            let exp_share = self.exp_share.as_ref();
+                                            let alive = |index: usize| party.get(index).map(|pokemon| !pokemon.fainted()).unwrap_or_default();
+                                            let benched = |index: usize| alive(index) && !active.iter().flatten().any(|active| active == &index);
+                                            let participants = fought.iter().filter(|index| alive(**index)).count();
+                                            let holders: Vec<usize> = party.iter().enumerate()
+                                                .filter(|(index, pokemon)| !fought.contains(index) && benched(*index) && pokemon.item.as_ref().map(|item| Some(&item.id) == exp_share).unwrap_or_default())
+                                                .map(|(index, ..)| index)
+                                                .collect();
+                                            let (participant, holder) = exp::share(experience, participants, holders.len());
+                                            let gains: Vec<_> = fought.iter().copied().filter(|index| benched(*index)).map(|index| (index, participant))
+                                                .chain(holders.into_iter().map(|index| (index, holder)))
+                                                .collect();
+                                            for (index, experience) in gains.into_iter().rev() {
+                                                queue.actions.push_front(Indexed(user_id.clone(), BattleClientGuiAction::BenchExp(index, experience)));
+                                            }
+                                            None
+                                        }
+                                        BattleClientGuiAction::BenchExp(index, experience) => {
+                                            drop(user);
+                                            drop(user_ui);
+                                            match self.local.player.pokemon.get_mut(index) {
+                                                Some(pokemon) => {
+                                                    let previous = exp::add(pokemon, experience);
+                                                    ui::text::on_gain_exp(&mut self.gui.text, &self.catalog, pokemon.name(), experience);
+                                                    if pokemon.level > previous {
+                                                        ui::text::on_level_up(&mut self.gui.text, &self.catalog, pokemon.name(), pokemon.level);
+                                                        evolution::check(&self.evolutions, &mut self.evolving, index, pokemon);
                                                     }
-                                                    true => None,
+                                                    let movedex = self.movedex;
+                                                    let moves: Vec<_> = pokemon.on_level_up(previous).flat_map(|id| movedex.try_get(&id)).collect();
+                                                    if !moves.is_empty() {
+                                                        queue.actions.push_front(Indexed(user_id.clone(), BattleClientGuiAction::LevelUp(index, moves, false)));
+                                                    }
+                                                    Some(BattleClientGuiCurrent::BenchExp)
                                                 }
+                                                None => None,
+                                            }
+                                        }
+                                        BattleClientGuiAction::LevelStats(index, level) => {
+                                            drop(user);
+                                            drop(user_ui);
+                                            match self.local.player.pokemon.get(index) {
+                                                Some(pokemon) => {
+                                                    ui::text::on_level_up(&mut self.gui.text, &self.catalog, pokemon.name(), level);
+                                                    self.gui.level_stats.spawn(&self.catalog, pokemon, level);
+                                                    Some(BattleClientGuiCurrent::LevelStats)
+                                                }
+                                                None => None,
+                                            }
+                                        }
+                                        BattleClientGuiAction::LevelUp(index, moves, synced) => {
+                                            drop(user);
+                                            drop(user_ui);
+                                            match self.local.player.pokemon.get_mut(index) {
+                                                Some(instance) => {
+                                                    match moves.is_empty() {
+                                                        false => {
+                                                            self.gui.level_up.spawn(instance, &mut self.gui.text, moves);
+                                                            Some(BattleClientGuiCurrent::LevelUp(index, synced))
+                                                        }
+                                                        true => None,
+                                                    }
+                                                }
+                                                None => None,
                                             }
-                                            None => None,
                                         }
                                         // ClientMove::Catch(index) => {
                                         //     if let Some(target) = match index.team {
@@ -609,7 +1123,7 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                     } {
                                         queue.current = Some(Indexed(user_id, action));
                                     } else {
-                                        self.update(ctx, dex, delta, bag);
+                                        self.update_battle(ctx, dex, delta, bag);
                                     }
                                 }
 
@@ -628,16 +1142,21 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                         match user {
                             Some((user, user_ui)) => match action {
                                 BattleClientGuiCurrent::Move(targets) => {
-    
-                                    // fix
+
+                                    let user_moves = &mut user_ui[user_id.index()].pokemon.moves;
+                                    let animating = !user_moves.finished() || !self.gui.animation.finished();
+                                    if animating {
+                                        user_moves.update(delta);
+                                        self.gui.animation.update(ctx, delta);
+                                    }
     
                                     match self.gui.text.finished() {
-                                        false => self.gui.text.update(ctx, delta),
-                                        true => if self.gui.text.page() > 0 || self.gui.text.waiting() {//&& user_ui[instance.pokemon.index].renderer.moves.finished() {
+                                        false => ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta),
+                                        true => if (self.gui.text.page() > 0 || self.gui.text.waiting()) && !animating {
 
                                             let targets = unsafe {&mut *(targets as *mut Vec<_>) };
 
-                                            targets.retain(|Indexed(location, ..)| {
+                                            targets.retain(|Indexed(location, action)| {
 
                                                 if let Some(target_ui) = if location.team() == self.local.player.id() {
                                                     Some(&mut self.local.renderer)
@@ -647,10 +1166,24 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
 
                                                     let ui = &mut target_ui[location.index()];
 
-                                                    let cont = ui.pokemon.flicker.flickering() || ui.status.health_moving();
+                                                    if ui.pokemon.flicker.waiting() {
+                                                        if let ClientMoveAction::SetHP(ClientDamage::Result(result)) = action {
+                                                            sound::play(ctx, self.sounds.hit(result.effective));
+                                                            if result.crit {
+                                                                sound::play(ctx, &self.sounds.crit);
+                                                            }
+                                                        }
+                                                        if !self.options.animations {
+                                                            ui.pokemon.flicker.remaining = 0;
+                                                            ui.status.finish_hp();
+                                                        }
+                                                    }
+
+                                                    let cont = ui.pokemon.flicker.flickering() || ui.status.health_moving() || ui.pokemon.stages.animating();
                                                     if cont {
                                                         ui.pokemon.flicker.update(delta);
                                                         ui.status.update_hp(delta);
+                                                        ui.pokemon.stages.update(delta);
                                                     }
                                                     cont
 
@@ -672,7 +1205,7 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                 },
                                 BattleClientGuiCurrent::Switch(new) => match self.gui.text.finished() {
                                     false => {
-                                        self.gui.text.update(ctx, delta);
+                                        ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta);
     
                                         if self.gui.text.page() == 1 && !user.active_eq(user_id.index(), Some(*new)) {
                                             user.replace(user_id.index(), Some(*new));
@@ -697,12 +1230,15 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                 },
                                 BattleClientGuiCurrent::UseItem(target) => {
                                     if !self.gui.text.finished() {
-                                        self.gui.text.update(ctx, delta)
+                                        ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta)
                                     } else if let Some((p, p_ui)) = match target.team() == self.local.player.id() {
                                         true => Some((&mut self.local.player as &mut PlayerView<'d, ID, AS>, &mut self.local.renderer)),
                                         false => self.remotes.get_mut(target.team()).map(|p| (&mut p.player as _, &mut p.renderer)),
                                     } {
                                         let target = &mut p_ui[target.index()].status;
+                                        if !self.options.animations {
+                                            target.finish_hp();
+                                        }
                                         if target.health_moving() {
                                             target.update_hp(delta);
                                         } else {
@@ -717,7 +1253,7 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                     if ui.pokemon.faint.fainting() {
                                         ui.pokemon.faint.update(delta);
                                     } else if !self.gui.text.finished() {
-                                        self.gui.text.update(ctx, delta);
+                                        ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta);
                                     } else {
                                         drop(user);
                                         match user_id.team() == self.local.player.id() && self.local.player.any_inactive() {
@@ -771,20 +1307,53 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                         *replaced = true;
                                     }
                                     match self.gui.text.finished() {
-                                        false => self.gui.text.update(ctx, delta),
+                                        false => ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta),
                                         true => queue.current = None,
                                     }
                                 }
                                 BattleClientGuiCurrent::Catch => match self.gui.text.finished() {
-                                    false => self.gui.text.update(ctx, delta),
+                                    false => ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta),
+                                    true => {
+                                        sound::play(ctx, &self.sounds.catch);
+                                        queue.current = None;
+                                    }
+                                }
+                                BattleClientGuiCurrent::LevelStats => match self.gui.text.finished() {
+                                    false => ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta),
+                                    true => match self.gui.level_stats.alive() {
+                                        true => self.gui.level_stats.input(ctx),
+                                        false => queue.current = None,
+                                    },
+                                }
+                                BattleClientGuiCurrent::BenchExp => match self.gui.text.finished() {
+                                    false => ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta),
                                     true => queue.current = None,
                                 }
+                                BattleClientGuiCurrent::Dialogue => {
+                                    self.gui.dialogue.update(delta);
+                                    if self.gui.dialogue.speaking() {
+                                        match self.gui.text.finished() {
+                                            false => ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta),
+                                            true => self.gui.dialogue.leave(),
+                                        }
+                                    } else if !self.gui.dialogue.alive() {
+                                        queue.current = None;
+                                    }
+                                }
                                 BattleClientGuiCurrent::SetExp => {
-                                    match !self.gui.text.finished() || self.local.renderer[user_id.index()].status.exp_moving() {
+                                    let filling = self.local.renderer[user_id.index()].status.exp_moving();
+                                    #[cfg(feature = "audio")]
+                                    {
+                                        self.exp_tick.set(filling);
+                                        self.exp_tick.update(ctx, delta, &self.sounds.exp);
+                                    }
+                                    match !self.gui.text.finished() || filling {
                                         true => {
-                                            self.gui.text.update(ctx, delta);
+                                            ui::text::advance(ctx, &mut self.gui.text, self.options.text_speed, delta);
                                             match self.local.player.active(user_id.index()) {
-                                                Some(pokemon) => self.local.renderer[user_id.index()].status.update_exp(delta, pokemon),
+                                                Some(pokemon) => if self.local.renderer[user_id.index()].status.update_exp(delta, pokemon) {
+                                                    sound::play(ctx, &self.sounds.level_up);
+                                                },
                                                 None => {
                                                     warn!("Could not get pokemon gaining exp at {:?}", user_id);
                                                     queue.current = None;
@@ -794,13 +1363,16 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                                         false => queue.current = None,
                                     }
                                 }
-                                BattleClientGuiCurrent::LevelUp => match self.gui.level_up.alive() {
-                                    true => match self.local.player.pokemon.get_mut(user_id.index()) {
-                                        Some(pokemon) => if let Some((index, move_ref)) = self.gui.level_up.update(ctx, &mut self.gui.text, delta, pokemon) {
-                                            self.client.send(ClientMessage::LearnMove(user_id.index(), move_ref.id, index as _));
+                                BattleClientGuiCurrent::LevelUp(party, synced) => match self.gui.level_up.alive() {
+                                    true => match self.local.player.pokemon.get_mut(*party) {
+                                        Some(pokemon) => if let Some((index, move_ref)) = self.gui.level_up.update(ctx, &mut self.gui.text, &self.catalog, self.options.text_speed, delta, pokemon) {
+                                            // the server cannot teach moves from level ups it did not make
+                                            if *synced {
+                                                self.client.send(ClientMessage::LearnMove(*party, move_ref.id, index as _));
+                                            }
                                         }
                                         None => {
-                                            warn!("Could not get user's active pokemon at {:?}", user_id);
+                                            warn!("Could not get party pokemon {} learning a move", party);
                                             queue.current = None;
                                         },
                                     },
@@ -817,21 +1389,27 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
     }
 
     pub fn draw(&self, ctx: &mut EngineContext, dex: &PokedexClientContext, party: &Party<OwnedPokemon<'d>>, bag: &Bag<'d>) {
-        if !matches!(self.state, BattlePlayerState::WaitToStart) {
+        if let BattlePlayerState::Entry(..) = self.state {
+            self.gui.entry.draw(ctx);
+        } else if !matches!(self.state, BattlePlayerState::WaitToStart) {
+            let shake = self.gui.animation.shake();
+            if shake != ZERO {
+                set_transform_matrix(ctx, Mat4::translation_2d(shake));
+            }
             self.gui.background.draw(ctx, 0.0);
             self.remotes.values().for_each(|remote| remote.renderer.iter().for_each(|active| active.draw(ctx)));
             match &self.state {
-                BattlePlayerState::WaitToStart => unreachable!(),
+                BattlePlayerState::WaitToStart | BattlePlayerState::Entry(..) => unreachable!(),
                 BattlePlayerState::Opening(..) => {
-                    self.gui.background.draw(ctx, self.gui.opener.offset::<ID, AS>());
-                    self.gui.opener.draw_below_panel::<ID, AS>(ctx, &self.local.renderer, &self.remotes.values().next().unwrap().renderer);
+                    self.gui.background.draw(ctx, self.gui.opener.offset());
+                    self.gui.opener.draw_below_panel(ctx, &self.local.renderer, &self.remotes.values().next().unwrap().renderer);
                     self.gui.trainer.draw(ctx);
                     self.gui.draw_panel(ctx);
-                    self.gui.opener.draw::<ID, AS>(ctx);
+                    self.gui.opener.draw(ctx);
                 }
                 BattlePlayerState::Introduction(..) => {
                     self.gui.background.draw(ctx, 0.0);
-                    self.gui.introduction.draw::<ID, AS>(ctx, &self.local.renderer, &self.remotes.values().next().unwrap().renderer);
+                    self.gui.introduction.draw(ctx, &self.local.renderer, &self.remotes.values().next().unwrap().renderer);
                     self.gui.trainer.draw(ctx);
                     self.gui.draw_panel(ctx);
                     self.gui.text.draw(ctx);
@@ -860,8 +1438,12 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                 // },
                 BattlePlayerState::WaitToSelect | BattlePlayerState::Moving(..) => {
                     self.local.renderer.iter().for_each(|active| active.draw(ctx));
+                    self.gui.animation.draw(ctx);
+                    self.gui.dialogue.draw(ctx);
+                    self.gui.trainer.draw(ctx);
                     self.gui.draw_panel(ctx);
                     self.gui.text.draw(ctx);
+                    self.gui.level_stats.draw(ctx);
                     self.gui.level_up.draw(ctx);
                     if self.party.alive() {
                         self.party.draw(ctx, party)
@@ -869,10 +1451,15 @@ impl<'d, ID: Default + Clone + Debug + Hash + Eq, const AS: usize> BattlePlayerG
                 },
                 BattlePlayerState::Winner(..) => {
                     self.local.renderer.iter().for_each(|active| active.draw(ctx));
+                    self.gui.outro.draw(ctx);
+                    self.gui.evolution.draw(ctx);
                     self.gui.draw_panel(ctx);
                     self.gui.text.draw(ctx);
                 }
             }
+            if shake != ZERO {
+                reset_transform_matrix(ctx);
+            }
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,200 @@
+use pokedex::engine::{
+    audio::{get_current_music, play_music, stop_music},
+    EngineContext,
+};
+
+use battle::BattleType;
+
+/// Chooses the music played during a battle.
+/// Hosts can replace any track before starting a battle.
+pub struct BattleMusic {
+    pub wild: BattleTrack,
+    pub trainer: BattleTrack,
+    pub gym: BattleTrack,
+
+    /// Played while the player's pokemon is low on health, if set.
+    pub low_health: Option<BattleTrack>,
+    /// Played when the opposing trainer is on their last pokemon, if set.
+    pub last_pokemon: Option<BattleTrack>,
+
+    pub wild_victory: BattleTrack,
+    pub trainer_victory: BattleTrack,
+    pub gym_victory: BattleTrack,
+
+    battle_type: BattleType,
+    state: BattleMusicState,
+    /// Time left in the intro of the current track.
+    intro: Option<f32>,
+    /// The music this controller last played.
+    playing: Option<String>,
+    /// Music the host was playing before the battle.
+    host: Option<String>,
+}
+
+/// A track that plays its intro once before looping its main part.
+#[derive(Debug, Clone)]
+pub struct BattleTrack {
+    /// Music played once first, and its length in seconds.
+    pub intro: Option<(String, f32)>,
+    pub looped: String,
+}
+
+impl BattleTrack {
+    pub fn new(intro: Option<(String, f32)>, looped: String) -> Self {
+        Self { intro, looped }
+    }
+}
+
+impl From<&str> for BattleTrack {
+    fn from(looped: &str) -> Self {
+        Self::new(None, looped.to_owned())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BattleMusicState {
+    None,
+    Main,
+    LowHealth,
+    LastPokemon,
+    /// Waiting to play the victory fanfare.
+    Won,
+    Victory,
+}
+
+impl Default for BattleMusic {
+    fn default() -> Self {
+        Self {
+            wild: "vs_wild".into(),
+            trainer: "vs_trainer".into(),
+            gym: "vs_gym".into(),
+            low_health: None,
+            last_pokemon: None,
+            wild_victory: "win_wild".into(),
+            trainer_victory: "win_trainer".into(),
+            gym_victory: "win_gym".into(),
+            battle_type: BattleType::Wild,
+            state: BattleMusicState::None,
+            intro: None,
+            playing: None,
+            host: None,
+        }
+    }
+}
+
+impl BattleMusic {
+    /// Starts the battle's main track, if it has not already started.
+    pub fn begin(&mut self, ctx: &EngineContext, battle_type: BattleType) {
+        if self.state != BattleMusicState::None {
+            return;
+        }
+        self.host = get_current_music(ctx).map(|id| id.to_string());
+        self.battle_type = battle_type;
+        self.set(ctx, BattleMusicState::Main);
+    }
+
+    /// If the battle's music has started.
+    pub fn started(&self) -> bool {
+        self.state != BattleMusicState::None
+    }
+
+    /// Switches between the main track and its variants, and from intros to their loops.
+    pub fn update(&mut self, ctx: &EngineContext, delta: f32, low_health: bool, last_pokemon: bool) {
+        match self.state {
+            BattleMusicState::None => return,
+            BattleMusicState::Won => self.set(ctx, BattleMusicState::Victory),
+            BattleMusicState::Victory => (),
+            BattleMusicState::Main | BattleMusicState::LowHealth | BattleMusicState::LastPokemon => {
+                let state = if low_health && self.low_health.is_some() {
+                    BattleMusicState::LowHealth
+                } else if last_pokemon && self.last_pokemon.is_some() {
+                    BattleMusicState::LastPokemon
+                } else {
+                    BattleMusicState::Main
+                };
+                self.set(ctx, state);
+            }
+        }
+        if let Some(intro) = self.intro.as_mut() {
+            *intro -= delta;
+            if *intro <= 0.0 {
+                self.intro = None;
+                if let Some(track) = self.track(self.state) {
+                    let looped = track.looped.clone();
+                    self.play(ctx, looped);
+                }
+            }
+        }
+    }
+
+    /// Plays the victory fanfare on the next update.
+    pub fn victory(&mut self) {
+        if self.started() && self.state != BattleMusicState::Victory {
+            self.state = BattleMusicState::Won;
+        }
+    }
+
+    /// Gives the music back to the host.
+    /// Music the host started during the battle is left playing.
+    pub fn end(&mut self, ctx: &EngineContext) {
+        if self.state != BattleMusicState::None {
+            self.state = BattleMusicState::None;
+            self.intro = None;
+            let current = get_current_music(ctx).map(|id| id.to_string());
+            if current.is_some() && current == self.playing {
+                match self.host.take() {
+                    Some(host) => self.play(ctx, host),
+                    None => stop_music(ctx),
+                }
+            }
+            self.playing = None;
+            self.host = None;
+        }
+    }
+
+    fn set(&mut self, ctx: &EngineContext, state: BattleMusicState) {
+        if self.state != state {
+            self.state = state;
+            if let Some(track) = self.track(state).cloned() {
+                match track.intro {
+                    Some((intro, length)) => {
+                        self.intro = Some(length);
+                        self.play(ctx, intro);
+                    }
+                    None => {
+                        self.intro = None;
+                        self.play(ctx, track.looped);
+                    }
+                }
+            }
+        }
+    }
+
+    fn track(&self, state: BattleMusicState) -> Option<&BattleTrack> {
+        Some(match state {
+            BattleMusicState::None | BattleMusicState::Won => return None,
+            BattleMusicState::Main => match self.battle_type {
+                BattleType::Wild => &self.wild,
+                BattleType::Trainer => &self.trainer,
+                BattleType::GymLeader => &self.gym,
+            },
+            BattleMusicState::LowHealth => self.low_health.as_ref()?,
+            BattleMusicState::LastPokemon => self.last_pokemon.as_ref()?,
+            BattleMusicState::Victory => match self.battle_type {
+                BattleType::Wild => &self.wild_victory,
+                BattleType::Trainer => &self.trainer_victory,
+                BattleType::GymLeader => &self.gym_victory,
+            },
+        })
+    }
+
+    fn play(&mut self, ctx: &EngineContext, track: String) {
+        match track.parse() {
+            Ok(id) => {
+                play_music(ctx, &id);
+                self.playing = Some(track);
+            }
+            Err(..) => log::warn!("Could not parse music id {}", track),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use pokedex::engine::input::Control;
+
+/// Options that change how the battle GUI plays out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BattleGuiOptions {
+    pub text_speed: TextSpeed,
+    /// If move animations, flickering, fainting and bouncing are played.
+    pub animations: bool,
+    /// Button held to play the battle faster.
+    pub fast_forward: Option<Control>,
+    /// How many times faster the battle plays while the fast forward button is held.
+    pub fast_forward_speed: f32,
+}
+
+impl Default for BattleGuiOptions {
+    fn default() -> Self {
+        Self {
+            text_speed: Default::default(),
+            animations: true,
+            fast_forward: None,
+            fast_forward_speed: 2.0,
+        }
+    }
+}
+
+/// How fast messages are written out and how long they wait before moving on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextSpeed {
+    Slow,
+    Mid,
+    Fast,
+    Instant,
+}
+
+impl TextSpeed {
+    /// Scales the time passed to a message box.
+    pub fn delta(self, delta: f32) -> f32 {
+        delta
+            * match self {
+                Self::Slow => 0.5,
+                Self::Mid => 1.0,
+                Self::Fast => 2.0,
+                Self::Instant => 100.0,
+            }
+    }
+
+    /// Scales the time passed to a message box while a page waits before moving on,
+    /// so waits shorten with the text speed without disappearing.
+    pub fn wait(self, delta: f32) -> f32 {
+        delta
+            * match self {
+                Self::Slow => 0.75,
+                Self::Mid => 1.0,
+                Self::Fast => 1.5,
+                Self::Instant => 3.0,
+            }
+    }
+}
+
+impl Default for TextSpeed {
+    fn default() -> Self {
+        Self::Mid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextSpeed;
+
+    #[test]
+    fn delta_scales_with_speed() {
+        assert_eq!(TextSpeed::Mid.delta(1.0), 1.0);
+        assert!(TextSpeed::Slow.delta(1.0) < TextSpeed::Mid.delta(1.0));
+        assert!(TextSpeed::Fast.delta(1.0) > TextSpeed::Mid.delta(1.0));
+        assert!(TextSpeed::Instant.delta(1.0) > TextSpeed::Fast.delta(1.0));
+    }
+
+    #[test]
+    fn instant_text_still_waits() {
+        assert!(TextSpeed::Instant.wait(1.0) < TextSpeed::Instant.delta(1.0));
+        assert!(TextSpeed::Slow.wait(1.0) < TextSpeed::Mid.wait(1.0));
+    }
+}
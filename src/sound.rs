@@ -0,0 +1,129 @@
+use pokedex::{
+    engine::{audio::sound::Sound, EngineContext},
+    types::Effective,
+};
+
+#[cfg(feature = "audio")]
+use pokedex::engine::audio::play_sound;
+
+/// Sound effects played by the battle GUI.
+/// Hosts can replace any of these before starting a battle, or set one to `None` to silence it.
+pub struct BattleSounds {
+    pub hit: Option<Sound>,
+    pub hit_super: Option<Sound>,
+    pub hit_weak: Option<Sound>,
+    pub crit: Option<Sound>,
+    pub faint: Option<Sound>,
+    pub stat_rise: Option<Sound>,
+    pub stat_fall: Option<Sound>,
+    /// Ticks while an exp bar fills.
+    pub exp: Option<Sound>,
+    pub level_up: Option<Sound>,
+    pub ball_throw: Option<Sound>,
+    pub ball_wobble: Option<Sound>,
+    pub catch: Option<Sound>,
+    pub run: Option<Sound>,
+    /// Looped while the player's pokemon is low on health.
+    pub low_health: Option<Sound>,
+}
+
+impl Default for BattleSounds {
+    fn default() -> Self {
+        Self {
+            hit: parse("hit"),
+            hit_super: parse("hit_sup"),
+            hit_weak: parse("hit_weak"),
+            crit: parse("crit"),
+            faint: parse("faint"),
+            stat_rise: parse("stat_up"),
+            stat_fall: parse("stat_dn"),
+            exp: parse("exp"),
+            level_up: parse("level_up"),
+            ball_throw: parse("throw"),
+            ball_wobble: parse("wobble"),
+            catch: parse("catch"),
+            run: parse("run"),
+            low_health: parse("low_hp"),
+        }
+    }
+}
+
+impl BattleSounds {
+    pub fn hit(&self, effective: Effective) -> &Option<Sound> {
+        match effective {
+            Effective::SuperEffective => &self.hit_super,
+            Effective::NotEffective | Effective::Ineffective => &self.hit_weak,
+            Effective::Effective => &self.hit,
+        }
+    }
+}
+
+/// Gets the sound with an id, such as one named in a move animation.
+pub fn parse(name: &str) -> Option<Sound> {
+    match name.parse() {
+        Ok(id) => Some(Sound::variant(id, None)),
+        Err(..) => {
+            log::warn!("Could not parse sound id {}", name);
+            None
+        }
+    }
+}
+
+/// Plays a sound, if there is one and the `audio` feature is enabled.
+#[cfg_attr(not(feature = "audio"), allow(unused_variables))]
+pub fn play(ctx: &EngineContext, sound: &Option<Sound>) {
+    #[cfg(feature = "audio")]
+    if let Some(sound) = sound {
+        play_sound(ctx, sound);
+    }
+}
+
+/// Replays a sound at an interval from when it is started until it is stopped,
+/// such as the low health alarm while the player's pokemon is in the red.
+#[cfg(feature = "audio")]
+pub struct RepeatingSound {
+    interval: f32,
+    remaining: f32,
+    playing: bool,
+}
+
+#[cfg(feature = "audio")]
+impl RepeatingSound {
+    pub const fn new(interval: f32) -> Self {
+        Self {
+            interval,
+            remaining: 0.0,
+            playing: false,
+        }
+    }
+
+    /// Starts repeating the sound, playing it on the next update. Does nothing if it is already repeating.
+    pub fn start(&mut self) {
+        if !self.playing {
+            self.playing = true;
+            self.remaining = 0.0;
+        }
+    }
+
+    pub fn stop(&mut self) {
+        self.playing = false;
+    }
+
+    /// Starts or stops repeating the sound.
+    pub fn set(&mut self, playing: bool) {
+        match playing {
+            true => self.start(),
+            false => self.stop(),
+        }
+    }
+
+    pub fn update(&mut self, ctx: &EngineContext, delta: f32, sound: &Option<Sound>) {
+        if self.playing {
+            self.remaining -= delta;
+            if self.remaining <= 0.0 {
+                play(ctx, sound);
+                self.remaining = self.interval;
+            }
+        }
+    }
+}
@@ -1,5 +1,8 @@
+pub mod entry;
 pub mod opener;
 pub mod introduction;
+pub mod outro;
+pub mod slide;
 
 pub mod trainer;
 
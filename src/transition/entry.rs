@@ -0,0 +1,137 @@
+use pokedex::engine::{
+    graphics::draw_rectangle,
+    tetra::graphics::Color,
+    util::WIDTH,
+    EngineContext,
+};
+
+use battle::BattleType;
+
+use crate::options::BattleGuiOptions;
+
+use super::TransitionState;
+
+/// How the screen is wiped to black after the entry flashes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryWipe {
+    Swirl,
+    Bars,
+    /// Black squares grow out of a checkerboard of cells.
+    Checker,
+    Fade,
+}
+
+/// White flashes followed by a wipe, drawn over the host's last frame before the battle opener.
+pub struct BattleEntryTransition {
+    /// Overrides the wipe chosen from the battle type and opponent strength.
+    pub wipe: Option<EntryWipe>,
+    current: EntryWipe,
+    counter: f32,
+}
+
+impl BattleEntryTransition {
+    const FLASH: f32 = 0.2;
+    const FLASHES: f32 = 3.0;
+    const WIPE: f32 = 1.0;
+
+    const HEIGHT: f32 = 160.0;
+    const CELL: f32 = 16.0;
+    const BARS: u8 = 8;
+
+    pub fn new() -> Self {
+        Self {
+            wipe: None,
+            current: EntryWipe::Fade,
+            counter: 0.0,
+        }
+    }
+
+    /// The wipe used for a battle when no override is set.
+    pub fn choose(battle_type: BattleType, stronger: bool) -> EntryWipe {
+        match battle_type {
+            BattleType::Wild => match stronger {
+                true => EntryWipe::Checker,
+                false => EntryWipe::Fade,
+            },
+            BattleType::Trainer => match stronger {
+                true => EntryWipe::Swirl,
+                false => EntryWipe::Bars,
+            },
+            BattleType::GymLeader => EntryWipe::Swirl,
+        }
+    }
+
+    /// Starts the transition, or skips it when animations are off.
+    pub fn begin(&mut self, state: &mut TransitionState, battle_type: BattleType, stronger: bool, options: &BattleGuiOptions) {
+        *state = match options.animations {
+            true => TransitionState::Run,
+            false => TransitionState::End,
+        };
+        self.current = self.wipe.unwrap_or_else(|| Self::choose(battle_type, stronger));
+        self.counter = 0.0;
+    }
+
+    pub fn update(&mut self, state: &mut TransitionState, delta: f32) {
+        self.counter += delta;
+        if self.counter >= Self::FLASH * Self::FLASHES + Self::WIPE {
+            *state = TransitionState::End;
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        let flashes = Self::FLASH * Self::FLASHES;
+        if self.counter < flashes {
+            let alpha = (self.counter % Self::FLASH / Self::FLASH * std::f32::consts::PI).sin();
+            draw_rectangle(ctx, 0.0, 0.0, WIDTH, Self::HEIGHT, Color::rgba(1.0, 1.0, 1.0, alpha));
+            return;
+        }
+        let progress = ((self.counter - flashes) / Self::WIPE).min(1.0);
+        match self.current {
+            EntryWipe::Fade => {
+                draw_rectangle(ctx, 0.0, 0.0, WIDTH, Self::HEIGHT, Color::rgba(0.0, 0.0, 0.0, progress))
+            }
+            EntryWipe::Bars => {
+                let height = Self::HEIGHT / Self::BARS as f32;
+                let width = WIDTH * progress;
+                for i in 0..Self::BARS {
+                    let x = match i % 2 == 0 {
+                        true => 0.0,
+                        false => WIDTH - width,
+                    };
+                    draw_rectangle(ctx, x, i as f32 * height, width, height, Color::BLACK);
+                }
+            }
+            EntryWipe::Checker | EntryWipe::Swirl => {
+                let columns = (WIDTH / Self::CELL).ceil() as u16;
+                let rows = (Self::HEIGHT / Self::CELL).ceil() as u16;
+                let center = (WIDTH / 2.0, Self::HEIGHT / 2.0);
+                let radius = (center.0 * center.0 + center.1 * center.1).sqrt();
+                for column in 0..columns {
+                    for row in 0..rows {
+                        let x = column as f32 * Self::CELL;
+                        let y = row as f32 * Self::CELL;
+                        match self.current {
+                            EntryWipe::Swirl => {
+                                // cells fill in order of their angle around the center, outer rings first
+                                let dx = x + Self::CELL / 2.0 - center.0;
+                                let dy = y + Self::CELL / 2.0 - center.1;
+                                let angle = (dy.atan2(dx) / std::f32::consts::TAU + 0.5) % 1.0;
+                                let ring = 1.0 - (dx * dx + dy * dy).sqrt() / radius;
+                                if progress * 2.0 >= angle + ring {
+                                    draw_rectangle(ctx, x, y, Self::CELL, Self::CELL, Color::BLACK);
+                                }
+                            }
+                            _ => {
+                                // cells grow from their centers, staggered in a checker pattern
+                                let offset = if (column + row) % 2 == 0 { 0.0 } else { 0.25 };
+                                let size = ((progress - offset) / 0.75).clamp(0.0, 1.0) * Self::CELL;
+                                let inset = (Self::CELL - size) / 2.0;
+                                draw_rectangle(ctx, x + inset, y + inset, size, size, Color::BLACK);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -7,7 +7,6 @@ use pokedex::{
             graphics::{Color, Rectangle, Texture},
             Context,
         },
-        text::MessagePage,
         util::{Completable, Entity, Reset},
         EngineContext,
     },
@@ -16,9 +15,12 @@ use pokedex::{
 use battle::{party::PlayerParty, BattleType};
 
 use crate::{
+    catalog::BattleCatalog,
     context::BattleGuiContext,
+    options::BattleGuiOptions,
     ui::{
         pokemon::PokemonStatusGui,
+        text::{advance, push},
         view::{ActiveRenderer, GuiLocalPlayer, GuiRemotePlayer},
     },
     view::GuiPokemonView,
@@ -50,41 +52,26 @@ impl BasicBattleIntroduction {
         }
     }
 
-    #[deprecated(note = "bad code, return vec of string (lines)")]
-    pub(crate) fn concatenate<'d, ID, P: GuiPokemonView<'d>, const AS: usize>(party: &PlayerParty<ID, usize, P, AS>) -> String {
-        let mut string = String::with_capacity(
-            party
-                .active_iter()
-                .map(|(.., p)| p.name().len() + 2)
-                .sum::<usize>()
-                + 2,
-        );
-        let len = party.active.len();
-        for (index, instance) in party.active_iter() {
-            if index != 0 {
-                if index == len - 2 {
-                    string.push_str(", ");
-                } else if index == len - 1 {
-                    string.push_str(" and ");
-                }
-            }
-            string.push_str(&instance.name());
-        }
-        string
+    /// Lists the names of a party's active pokemon.
+    pub(crate) fn concatenate<'d, ID, P: GuiPokemonView<'d>, const AS: usize>(catalog: &BattleCatalog, party: &PlayerParty<ID, usize, P, AS>) -> String {
+        let names: Vec<&str> = party.active_iter().map(|(.., pokemon)| pokemon.name()).collect();
+        catalog.list(&names)
     }
 
-    pub(crate) fn common_setup<ID: Default, const AS: usize>(
+    pub fn common_setup<ID: Default, const AS: usize>(
         &mut self,
+        catalog: &BattleCatalog,
         text: &mut MessageBox,
         player: &GuiLocalPlayer<ID, AS>,
     ) {
-        text.push(MessagePage {
-            lines: vec![format!("Go! {}!", Self::concatenate(&player.player))],
-            wait: Some(0.5),
-        });
+        push(
+            text,
+            catalog.get("intro.go", &[("pokemon", &Self::concatenate(catalog, &player.player))]),
+            Some(0.5),
+        );
     }
 
-    pub(crate) fn draw_player<const AS: usize>(&self, ctx: &mut Context, player: &ActiveRenderer<AS>) {
+    pub fn draw_player<const AS: usize>(&self, ctx: &mut Context, player: &ActiveRenderer<AS>) {
         if self.counter < Self::PLAYER_DESPAWN {
             self.player.draw_region(
                 ctx,
@@ -116,7 +103,7 @@ impl BasicBattleIntroduction {
         }
     }
 
-    pub(crate) fn draw_opponent<const AS: usize>(&self, ctx: &mut EngineContext, opponent: &ActiveRenderer<AS>) {
+    pub fn draw_opponent<const AS: usize>(&self, ctx: &mut EngineContext, opponent: &ActiveRenderer<AS>) {
         for active in opponent.iter() {
             active.pokemon.draw(ctx, ZERO, Color::WHITE);
             active.status.draw(ctx, self.offsets.0, 0.0);
@@ -147,31 +134,31 @@ impl<ID: Default, const AS: usize> BattleIntroduction<ID, AS> for BasicBattleInt
     fn spawn(
         &mut self,
         _: &PokedexClientContext,
+        catalog: &BattleCatalog,
         _: BattleType,
         player: &GuiLocalPlayer<ID, AS>,
         opponent: &GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
     ) {
         text.clear();
-        text.push(MessagePage {
-            lines: vec![format!(
-                "Wild {} appeared!",
-                Self::concatenate(&opponent.player)
-            )],
-            wait: None,
-        });
-        self.common_setup(text, player);
+        push(
+            text,
+            catalog.get("intro.wild", &[("pokemon", &Self::concatenate(catalog, &opponent.player))]),
+            None,
+        );
+        self.common_setup(catalog, text, player);
     }
 
     fn update(
         &mut self,
         ctx: &EngineContext,
         delta: f32,
+        options: &BattleGuiOptions,
         player: &mut GuiLocalPlayer<ID, AS>,
         opponent: &mut GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
     ) {
-        text.update(ctx, delta);
+        advance(ctx, text, options.text_speed, delta);
 
         if text.page() + 1 == text.pages() && self.counter < Self::PLAYER_DESPAWN {
             self.counter += delta * 180.0;
@@ -192,7 +179,10 @@ impl<ID: Default, const AS: usize> BattleIntroduction<ID, AS> for BasicBattleInt
         if let Some(active) = player.renderer.get(0) {
             if active.pokemon.spawner.spawning() {
                 for active in player.renderer.iter_mut() {
-                    active.pokemon.spawner.update(ctx, delta);
+                    match options.animations {
+                        true => active.pokemon.spawner.update(ctx, delta),
+                        false => active.pokemon.spawner.finish(ctx),
+                    }
                 }
             } else if active.status.alive() {
                 self.offsets1(delta);
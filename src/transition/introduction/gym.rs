@@ -0,0 +1,79 @@
+use pokedex::{
+    context::PokedexClientContext,
+    engine::{
+        gui::MessageBox,
+        util::{Completable, Reset},
+        EngineContext,
+    },
+};
+
+use battle::BattleType;
+
+use crate::{
+    catalog::BattleCatalog,
+    context::BattleGuiContext,
+    options::BattleGuiOptions,
+    ui::view::{ActiveRenderer, GuiLocalPlayer, GuiRemotePlayer},
+};
+
+use super::{BattleIntroduction, TrainerBattleIntroduction};
+
+/// A trainer introduction where the gym leader says their pre-battle dialogue first.
+pub struct GymLeaderBattleIntroduction {
+    introduction: TrainerBattleIntroduction,
+}
+
+impl GymLeaderBattleIntroduction {
+    pub fn new(ctx: &BattleGuiContext) -> Self {
+        Self {
+            introduction: TrainerBattleIntroduction::new(ctx),
+        }
+    }
+}
+
+impl<ID: Default, const AS: usize> BattleIntroduction<ID, AS> for GymLeaderBattleIntroduction {
+    fn spawn(
+        &mut self,
+        ctx: &PokedexClientContext,
+        catalog: &BattleCatalog,
+        _battle_type: BattleType,
+        player: &GuiLocalPlayer<ID, AS>,
+        opponent: &GuiRemotePlayer<ID, AS>,
+        text: &mut MessageBox,
+    ) {
+        text.clear();
+        for page in opponent.dialogue.before.iter() {
+            text.push(page.clone());
+        }
+        self.introduction.challenge(ctx, catalog, opponent, text);
+        self.introduction.common_setup(catalog, text, player);
+    }
+
+    fn update(
+        &mut self,
+        ctx: &EngineContext,
+        delta: f32,
+        options: &BattleGuiOptions,
+        player: &mut GuiLocalPlayer<ID, AS>,
+        opponent: &mut GuiRemotePlayer<ID, AS>,
+        text: &mut MessageBox,
+    ) {
+        BattleIntroduction::update(&mut self.introduction, ctx, delta, options, player, opponent, text);
+    }
+
+    fn draw(&self, ctx: &mut EngineContext, player: &ActiveRenderer<AS>, opponent: &ActiveRenderer<AS>) {
+        BattleIntroduction::<ID, AS>::draw(&self.introduction, ctx, player, opponent);
+    }
+}
+
+impl Completable for GymLeaderBattleIntroduction {
+    fn finished(&self) -> bool {
+        self.introduction.finished()
+    }
+}
+
+impl Reset for GymLeaderBattleIntroduction {
+    fn reset(&mut self) {
+        self.introduction.reset();
+    }
+}
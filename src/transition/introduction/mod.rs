@@ -1,3 +1,5 @@
+use hashbrown::HashMap;
+
 use pokedex::{
     context::PokedexClientContext,
     engine::{
@@ -5,26 +7,34 @@ use pokedex::{
         util::{Completable, Entity},
         EngineContext,
     },
+    TrainerId,
 };
 
-use battle::BattleType;
+use battle::{BattleData, BattleType};
 
 use crate::{
+    catalog::BattleCatalog,
     context::BattleGuiContext,
+    options::BattleGuiOptions,
     ui::view::{ActiveRenderer, GuiLocalPlayer, GuiRemotePlayer},
 };
 
 use super::TransitionState;
 
 mod basic;
+mod gym;
 mod trainer;
 
 pub use basic::*;
+pub use gym::*;
 pub use trainer::*;
 
 pub enum Introductions {
     Basic,
     Trainer,
+    GymLeader,
+    /// An introduction registered by the host.
+    Custom(String),
 }
 
 impl Default for Introductions {
@@ -33,10 +43,11 @@ impl Default for Introductions {
     }
 }
 
-pub(crate) trait BattleIntroduction<ID: Default, const AS: usize>: Completable {
+pub trait BattleIntroduction<ID: Default, const AS: usize>: Completable {
     fn spawn(
         &mut self,
         ctx: &PokedexClientContext,
+        catalog: &BattleCatalog,
         battle_type: BattleType,
         player: &GuiLocalPlayer<ID, AS>,
         opponent: &GuiRemotePlayer<ID, AS>,
@@ -47,6 +58,7 @@ pub(crate) trait BattleIntroduction<ID: Default, const AS: usize>: Completable {
         &mut self,
         ctx: &EngineContext,
         delta: f32,
+        options: &BattleGuiOptions,
         player: &mut GuiLocalPlayer<ID, AS>,
         opponent: &mut GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
@@ -55,40 +67,78 @@ pub(crate) trait BattleIntroduction<ID: Default, const AS: usize>: Completable {
     fn draw(&self, ctx: &mut EngineContext, player: &ActiveRenderer<AS>, opponent: &ActiveRenderer<AS>);
 }
 
-pub struct BattleIntroductionManager {
+/// Chooses a custom introduction by name for a battle, or the default introduction if `None` is returned.
+pub type IntroductionSelector = dyn Fn(&BattleData, Option<&TrainerId>) -> Option<String>;
+
+pub struct BattleIntroductionManager<ID: Default, const AS: usize> {
     current: Introductions,
 
     basic: BasicBattleIntroduction,
     trainer: TrainerBattleIntroduction,
+    gym: GymLeaderBattleIntroduction,
+
+    custom: HashMap<String, Box<dyn BattleIntroduction<ID, AS>>>,
+    selector: Option<Box<IntroductionSelector>>,
 }
 
-impl BattleIntroductionManager {
+impl<ID: Default, const AS: usize> BattleIntroductionManager<ID, AS> {
     pub fn new(ctx: &BattleGuiContext) -> Self {
         Self {
             current: Introductions::default(),
 
             basic: BasicBattleIntroduction::new(ctx),
             trainer: TrainerBattleIntroduction::new(ctx),
+            gym: GymLeaderBattleIntroduction::new(ctx),
+
+            custom: HashMap::new(),
+            selector: None,
         }
     }
 
-    pub fn begin<ID: Default, const AS: usize>(
+    /// Adds an introduction that can be chosen by name.
+    pub fn register(&mut self, name: impl Into<String>, introduction: impl BattleIntroduction<ID, AS> + 'static) {
+        self.custom.insert(name.into(), Box::new(introduction));
+    }
+
+    /// Sets the callback used to choose a registered introduction for each battle.
+    pub fn select(&mut self, selector: impl Fn(&BattleData, Option<&TrainerId>) -> Option<String> + 'static) {
+        self.selector = Some(Box::new(selector));
+    }
+
+    pub fn begin(
         &mut self,
         ctx: &PokedexClientContext,
+        catalog: &BattleCatalog,
         state: &mut TransitionState,
-        battle_type: BattleType,
+        data: &BattleData,
         player: &GuiLocalPlayer<ID, AS>,
         opponent: &GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
     ) {
         *state = TransitionState::Run;
-        match battle_type {
-            BattleType::Wild => self.current = Introductions::Basic,
-            _ => self.current = Introductions::Trainer,
-        }
+        let custom = self
+            .selector
+            .as_ref()
+            .map(|selector| selector(data, opponent.trainer.as_ref()))
+            .flatten()
+            .filter(|name| match self.custom.contains_key(name) {
+                true => true,
+                false => {
+                    log::warn!("Could not find battle introduction named {}", name);
+                    false
+                }
+            });
+        self.current = match custom {
+            Some(name) => Introductions::Custom(name),
+            None => match data.type_ {
+                BattleType::Wild => Introductions::Basic,
+                BattleType::Trainer => Introductions::Trainer,
+                BattleType::GymLeader => Introductions::GymLeader,
+            },
+        };
         let current = self.get_mut();
         current.reset();
-        current.spawn(ctx, battle_type, player, opponent, text);
+        current.spawn(ctx, catalog, data.type_, player, opponent, text);
         text.spawn();
     }
 
@@ -96,42 +146,53 @@ impl BattleIntroductionManager {
         text.clear();
     }
 
-    pub fn update<ID: Default, const AS: usize>(
+    pub fn update(
         &mut self,
         state: &mut TransitionState,
         ctx: &EngineContext,
         delta: f32,
+        options: &BattleGuiOptions,
         player: &mut GuiLocalPlayer<ID, AS>,
         opponent: &mut GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
     ) {
         let current = self.get_mut();
-        current.update(ctx, delta, player, opponent, text);
+        current.update(ctx, delta, options, player, opponent, text);
         if current.finished() {
             *state = TransitionState::End;
         }
     }
 
-    pub fn draw<ID: Default, const AS: usize>(
+    pub fn draw(
         &self,
         ctx: &mut EngineContext,
         player: &ActiveRenderer<AS>,
         opponent: &ActiveRenderer<AS>,
     ) {
-        self.get::<ID, AS>().draw(ctx, player, opponent);
+        self.get().draw(ctx, player, opponent);
     }
 
-    fn get<ID: Default, const AS: usize>(&self) -> &dyn BattleIntroduction<ID, AS> {
-        match self.current {
+    fn get(&self) -> &dyn BattleIntroduction<ID, AS> {
+        match &self.current {
             Introductions::Basic => &self.basic,
             Introductions::Trainer => &self.trainer,
+            Introductions::GymLeader => &self.gym,
+            Introductions::Custom(name) => match self.custom.get(name) {
+                Some(introduction) => introduction.as_ref(),
+                None => &self.basic,
+            },
         }
     }
 
-    fn get_mut<ID: Default, const AS: usize>(&mut self) -> &mut dyn BattleIntroduction<ID, AS> {
-        match self.current {
+    fn get_mut(&mut self) -> &mut dyn BattleIntroduction<ID, AS> {
+        match &self.current {
             Introductions::Basic => &mut self.basic,
             Introductions::Trainer => &mut self.trainer,
+            Introductions::GymLeader => &mut self.gym,
+            Introductions::Custom(name) => match self.custom.get_mut(name) {
+                Some(introduction) => introduction.as_mut(),
+                None => &mut self.basic,
+            },
         }
     }
 }
@@ -4,7 +4,6 @@ use pokedex::{
         graphics::draw_o_bottom,
         gui::MessageBox,
         tetra::graphics::Texture,
-        text::MessagePage,
         util::{Completable, Reset},
         EngineContext,
     },
@@ -13,8 +12,10 @@ use pokedex::{
 use battle::BattleType;
 
 use crate::{
+    catalog::BattleCatalog,
     context::BattleGuiContext,
-    ui::view::{ActiveRenderer, GuiLocalPlayer, GuiRemotePlayer},
+    options::BattleGuiOptions,
+    ui::{text::push, view::{ActiveRenderer, GuiLocalPlayer, GuiRemotePlayer}},
 };
 
 use super::{basic::BasicBattleIntroduction, BattleIntroduction};
@@ -44,49 +45,27 @@ impl<ID: Default, const AS: usize> BattleIntroduction<ID, AS> for TrainerBattleI
     fn spawn(
         &mut self,
         ctx: &PokedexClientContext,
+        catalog: &BattleCatalog,
         _battle_type: BattleType,
         player: &GuiLocalPlayer<ID, AS>,
         opponent: &GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
     ) {
         text.clear();
-
-        if let Some(id) = &opponent.trainer {
-            self.texture = Some(ctx.trainer_textures.get(id).clone());
-        }
-
-        if let Some(name) = &opponent.player.name {
-            text.push(MessagePage {
-                lines: vec![name.to_owned(), "would like to battle!".to_owned()],
-                wait: None,
-            });
-
-            text.push(MessagePage {
-                lines: vec![
-                    format!("{} sent", name),
-                    format!("out {}", BasicBattleIntroduction::concatenate(&opponent.player)),
-                ],
-                wait: Some(0.5),
-            });
-        } else {
-            text.push(MessagePage {
-                lines: vec![String::from("No trainer data found!")],
-                wait: None,
-            });
-        }
-
-        self.introduction.common_setup(text, player);
+        self.challenge(ctx, catalog, opponent, text);
+        self.introduction.common_setup(catalog, text, player);
     }
 
     fn update(
         &mut self,
         ctx: &EngineContext,
         delta: f32,
+        options: &BattleGuiOptions,
         player: &mut GuiLocalPlayer<ID, AS>,
         opponent: &mut GuiRemotePlayer<ID, AS>,
         text: &mut MessageBox,
     ) {
-        self.introduction.update(ctx, delta, player, opponent, text);
+        self.introduction.update(ctx, delta, options, player, opponent, text);
         if text.waiting() && text.page() == text.pages() - 2 {
             self.leaving = true;
         }
@@ -105,6 +84,44 @@ impl<ID: Default, const AS: usize> BattleIntroduction<ID, AS> for TrainerBattleI
     }
 }
 
+impl TrainerBattleIntroduction {
+    /// Loads the trainer's texture and pushes the challenge and send out pages.
+    pub(crate) fn challenge<ID, const AS: usize>(
+        &mut self,
+        ctx: &PokedexClientContext,
+        catalog: &BattleCatalog,
+        opponent: &GuiRemotePlayer<ID, AS>,
+        text: &mut MessageBox,
+    ) {
+        if let Some(id) = &opponent.trainer {
+            self.texture = Some(ctx.trainer_textures.get(id).clone());
+        }
+
+        if let Some(name) = &opponent.player.name {
+            push(text, catalog.get("intro.challenge", &[("trainer", name)]), None);
+            push(
+                text,
+                catalog.get(
+                    "intro.send_out",
+                    &[("trainer", name), ("pokemon", &BasicBattleIntroduction::concatenate(catalog, &opponent.player))],
+                ),
+                Some(0.5),
+            );
+        } else {
+            push(text, catalog.get("intro.no_trainer", &[]), None);
+        }
+    }
+
+    pub(crate) fn common_setup<ID: Default, const AS: usize>(
+        &mut self,
+        catalog: &BattleCatalog,
+        text: &mut MessageBox,
+        player: &GuiLocalPlayer<ID, AS>,
+    ) {
+        self.introduction.common_setup(catalog, text, player);
+    }
+}
+
 impl Completable for TrainerBattleIntroduction {
     fn finished(&self) -> bool {
         self.introduction.finished()
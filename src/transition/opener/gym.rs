@@ -0,0 +1,100 @@
+use pokedex::{
+    context::PokedexClientContext,
+    engine::{
+        graphics::{draw_o_bottom, draw_rectangle, position},
+        tetra::graphics::{Color, Texture},
+        util::{Completable, Reset, WIDTH},
+        EngineContext,
+    },
+};
+
+use crate::{context::BattleGuiContext, ui::view::{ActiveRenderer, GuiRemotePlayer}};
+
+use super::{BattleOpener, DefaultBattleOpener};
+
+/// Slides the gym leader's portrait across a split screen and flashes before the usual opener.
+pub struct GymLeaderBattleOpener {
+    opener: DefaultBattleOpener,
+    trainer: Option<Texture>,
+    counter: f32,
+}
+
+impl GymLeaderBattleOpener {
+    const SLIDE: f32 = 0.4;
+    const HOLD: f32 = Self::SLIDE + 1.0;
+    const FLASH: f32 = Self::HOLD + 0.3;
+
+    const BAND_HEIGHT: f32 = 40.0;
+    const PORTRAIT_X: f32 = 144.0;
+
+    const TOP: Color = Color::rgb(248.0 / 255.0, 200.0 / 255.0, 56.0 / 255.0);
+    const BOTTOM: Color = Color::rgb(56.0 / 255.0, 80.0 / 255.0, 200.0 / 255.0);
+
+    pub fn new(ctx: &BattleGuiContext) -> Self {
+        Self {
+            opener: DefaultBattleOpener::new(ctx),
+            trainer: None,
+            counter: 0.0,
+        }
+    }
+}
+
+impl<ID: Default, const AS: usize> BattleOpener<ID, AS> for GymLeaderBattleOpener {
+    fn spawn(&mut self, ctx: &PokedexClientContext, opponent: &GuiRemotePlayer<ID, AS>) {
+        if let Some(id) = &opponent.trainer {
+            self.trainer = Some(ctx.trainer_textures.get(id).clone());
+        }
+    }
+
+    fn update(&mut self, delta: f32) {
+        match self.counter < Self::FLASH {
+            true => self.counter += delta,
+            false => self.opener.update(delta),
+        }
+    }
+
+    fn draw_below_panel(
+        &self,
+        ctx: &mut EngineContext,
+        player: &ActiveRenderer<AS>,
+        opponent: &ActiveRenderer<AS>,
+    ) {
+        draw_o_bottom(ctx, self.trainer.as_ref(), 144.0 - self.opener.offset, 74.0);
+        self.opener.draw_below_panel(ctx, player, opponent);
+    }
+
+    fn draw(&self, ctx: &mut EngineContext) {
+        self.opener.draw(ctx);
+        if self.counter < Self::HOLD {
+            let slide = (self.counter / Self::SLIDE).min(1.0);
+            let y = 80.0 - Self::BAND_HEIGHT;
+            draw_rectangle(ctx, WIDTH * (slide - 1.0), y, WIDTH, Self::BAND_HEIGHT, Self::TOP);
+            draw_rectangle(ctx, WIDTH * (1.0 - slide), 80.0, WIDTH, Self::BAND_HEIGHT, Self::BOTTOM);
+            if let Some(trainer) = &self.trainer {
+                let x = Self::PORTRAIT_X + (WIDTH - Self::PORTRAIT_X) * (1.0 - slide);
+                trainer.draw(ctx, position(x - trainer.width() as f32 / 2.0, 80.0 + Self::BAND_HEIGHT - trainer.height() as f32));
+            }
+        } else if self.counter < Self::FLASH {
+            let alpha = 1.0 - (self.counter - Self::HOLD) / (Self::FLASH - Self::HOLD);
+            draw_rectangle(ctx, 0.0, 0.0, WIDTH, 160.0, Color::rgba(1.0, 1.0, 1.0, alpha));
+        }
+    }
+
+    fn offset(&self) -> f32 {
+        self.opener.offset
+    }
+}
+
+impl Reset for GymLeaderBattleOpener {
+    fn reset(&mut self) {
+        self.opener.reset();
+        self.trainer = None;
+        self.counter = 0.0;
+    }
+}
+
+impl Completable for GymLeaderBattleOpener {
+    fn finished(&self) -> bool {
+        self.counter >= Self::FLASH && self.opener.finished()
+    }
+}
@@ -1,49 +1,90 @@
+use hashbrown::HashMap;
+
 use pokedex::{
     context::PokedexClientContext,
-    engine::{tetra::Context, EngineContext},
+    engine::EngineContext,
+    TrainerId,
 };
 
-use battle::BattleType;
+use battle::{BattleData, BattleType};
 
 use crate::{
     context::BattleGuiContext,
     transition::TransitionState,
-    ui::view::{ActiveRenderer, GuiRemotePlayer},
+    ui::{background::BattleBackground, view::{ActiveRenderer, GuiRemotePlayer}},
 };
 
-use super::{BattleOpener, Openers, TrainerBattleOpener, WildBattleOpener};
+use super::{BattleOpener, GymLeaderBattleOpener, Openers, TrainerBattleOpener, WildBattleOpener};
+
+/// Chooses a custom opener by name for a battle, or the default opener if `None` is returned.
+pub type OpenerSelector = dyn Fn(&BattleData, Option<&TrainerId>) -> Option<String>;
 
-pub struct BattleOpenerManager {
+pub struct BattleOpenerManager<ID, const AS: usize> {
     current: Openers,
 
     wild: WildBattleOpener,
     trainer: TrainerBattleOpener,
+    gym: GymLeaderBattleOpener,
+
+    custom: HashMap<String, Box<dyn BattleOpener<ID, AS>>>,
+    selector: Option<Box<OpenerSelector>>,
 }
 
-impl BattleOpenerManager {
-    pub fn new(ctx: &mut Context, gui: &BattleGuiContext) -> Self {
+impl<ID: Default, const AS: usize> BattleOpenerManager<ID, AS> {
+    pub fn new(gui: &BattleGuiContext) -> Self {
         Self {
             current: Openers::default(),
 
-            wild: WildBattleOpener::new(ctx, gui),
+            wild: WildBattleOpener::new(gui),
             trainer: TrainerBattleOpener::new(gui),
+            gym: GymLeaderBattleOpener::new(gui),
+
+            custom: HashMap::new(),
+            selector: None,
         }
     }
 
-    pub fn begin<ID: Default, const AS: usize>(
+    /// Adds an opener that can be chosen by name.
+    pub fn register(&mut self, name: impl Into<String>, opener: impl BattleOpener<ID, AS> + 'static) {
+        self.custom.insert(name.into(), Box::new(opener));
+    }
+
+    /// Sets the callback used to choose a registered opener for each battle.
+    pub fn select(&mut self, selector: impl Fn(&BattleData, Option<&TrainerId>) -> Option<String> + 'static) {
+        self.selector = Some(Box::new(selector));
+    }
+
+    pub fn begin(
         &mut self,
         ctx: &PokedexClientContext,
         state: &mut TransitionState,
-        battle_type: BattleType,
+        data: &BattleData,
+        background: &BattleBackground,
         opponent: &GuiRemotePlayer<ID, AS>,
     ) {
         *state = TransitionState::Run;
-        self.current = match battle_type {
-            BattleType::Wild => Openers::Wild,
-            BattleType::Trainer => Openers::Trainer,
-            BattleType::GymLeader => Openers::Trainer,
+        self.wild.overlay(background.overlay().map(|(texture, color)| (texture.clone(), color)));
+        let custom = self
+            .selector
+            .as_ref()
+            .map(|selector| selector(data, opponent.trainer.as_ref()))
+            .flatten()
+            .filter(|name| match self.custom.contains_key(name) {
+                true => true,
+                false => {
+                    log::warn!("Could not find battle opener named {}", name);
+                    false
+                }
+            });
+        self.current = match custom {
+            Some(name) => Openers::Custom(name),
+            None => match data.type_ {
+                BattleType::Wild => Openers::Wild,
+                BattleType::Trainer => Openers::Trainer,
+                BattleType::GymLeader => Openers::GymLeader,
+            },
         };
-        let current = self.get_mut::<ID, AS>();
+        let current = self.get_mut();
         current.reset();
         current.spawn(ctx, opponent);
     }
@@ -52,42 +93,52 @@ impl BattleOpenerManager {
     //     *state = TransitionState::Begin;
     // }
 
-    pub fn update<ID: Default, const AS: usize>(&mut self, state: &mut TransitionState, delta: f32) {
-        let current = self.get_mut::<ID, AS>();
+    pub fn update(&mut self, state: &mut TransitionState, delta: f32) {
+        let current = self.get_mut();
         current.update(delta);
         if current.finished() {
             *state = TransitionState::End;
         }
     }
 
-    pub fn draw_below_panel<ID: Default, const AS: usize>(
+    pub fn draw_below_panel(
         &self,
         ctx: &mut EngineContext,
         player: &ActiveRenderer<AS>,
         opponent: &ActiveRenderer<AS>,
     ) {
-        self.get::<ID, AS>().draw_below_panel(ctx, player, opponent);
+        self.get().draw_below_panel(ctx, player, opponent);
     }
 
-    pub fn draw<ID: Default, const AS: usize>(&self, ctx: &mut EngineContext) {
-        self.get::<ID, AS>().draw(ctx);
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        self.get().draw(ctx);
     }
 
-    pub fn offset<ID: Default, const AS: usize>(&self) -> f32 {
-        self.get::<ID, AS>().offset()
+    pub fn offset(&self) -> f32 {
+        self.get().offset()
     }
 
-    fn get<ID: Default, const AS: usize>(&self) -> &dyn BattleOpener<ID, AS> {
-        match self.current {
+    fn get(&self) -> &dyn BattleOpener<ID, AS> {
+        match &self.current {
             Openers::Wild => &self.wild,
             Openers::Trainer => &self.trainer,
+            Openers::GymLeader => &self.gym,
+            Openers::Custom(name) => match self.custom.get(name) {
+                Some(opener) => opener.as_ref(),
+                None => &self.wild,
+            },
         }
     }
 
-    fn get_mut<ID: Default, const AS: usize>(&mut self) -> &mut dyn BattleOpener<ID, AS> {
-        match self.current {
+    fn get_mut(&mut self) -> &mut dyn BattleOpener<ID, AS> {
+        match &self.current {
             Openers::Wild => &mut self.wild,
             Openers::Trainer => &mut self.trainer,
+            Openers::GymLeader => &mut self.gym,
+            Openers::Custom(name) => match self.custom.get_mut(name) {
+                Some(opener) => opener.as_mut(),
+                None => &mut self.wild,
+            },
         }
     }
 }
@@ -12,16 +12,21 @@ use crate::{context::BattleGuiContext, ui::view::{ActiveRenderer, GuiRemotePlaye
 
 mod manager;
 
+mod gym;
 mod trainer;
 mod wild;
 
-pub use manager::BattleOpenerManager;
+pub use manager::{BattleOpenerManager, OpenerSelector};
+pub use gym::GymLeaderBattleOpener;
 pub use trainer::TrainerBattleOpener;
 pub use wild::WildBattleOpener;
 
 pub enum Openers {
     Wild,
     Trainer,
+    GymLeader,
+    /// An opener registered by the host.
+    Custom(String),
 }
 
 impl Default for Openers {
@@ -30,7 +35,7 @@ impl Default for Openers {
     }
 }
 
-pub(crate) trait BattleOpener<ID, const AS: usize>: Completable {
+pub trait BattleOpener<ID, const AS: usize>: Completable {
     fn spawn(&mut self, ctx: &PokedexClientContext, opponent: &GuiRemotePlayer<ID, AS>);
 
     fn update(&mut self, delta: f32);
@@ -1,9 +1,9 @@
 use pokedex::{
     context::PokedexClientContext,
     engine::{
-        graphics::{byte_texture, position, LIGHTGRAY},
-        tetra::{graphics::Texture, math::Vec2, Context},
-        util::{Completable, Reset},
+        graphics::{position, LIGHTGRAY},
+        tetra::{graphics::{Color, Texture}, math::Vec2},
+        util::{Completable, Reset, WIDTH},
         EngineContext,
     },
 };
@@ -18,18 +18,29 @@ use super::{BattleOpener, DefaultBattleOpener};
 pub struct WildBattleOpener {
     opener: DefaultBattleOpener,
 
-    grass: Texture,
+    overlay: Option<(Texture, Color)>,
     offset: Vec2<f32>,
 }
 
 impl WildBattleOpener {
-    const GRASS_WIDTH: f32 = 128.0;
-    const GRASS_HEIGHT: f32 = 47.0;
-    pub fn new(ctx: &mut Context, gui: &BattleGuiContext) -> Self {
+    pub fn new(gui: &BattleGuiContext) -> Self {
         Self {
             opener: DefaultBattleOpener::new(gui),
-            grass: byte_texture(ctx, include_bytes!("../../../assets/grass.png")),
-            offset: Vec2::new(Self::GRASS_WIDTH, Self::GRASS_HEIGHT),
+            overlay: None,
+            offset: Vec2::zero(),
+        }
+    }
+
+    /// Sets the texture that scrolls across the screen, such as the tall grass of the battle's terrain.
+    pub fn overlay(&mut self, overlay: Option<(Texture, Color)>) {
+        self.overlay = overlay;
+        self.offset = self.size();
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        match &self.overlay {
+            Some((texture, ..)) => Vec2::new(texture.width() as f32, texture.height() as f32),
+            None => Vec2::zero(),
         }
     }
 }
@@ -41,8 +52,9 @@ impl<ID, const AS: usize> BattleOpener<ID, AS> for WildBattleOpener {
         self.opener.update(delta);
         if self.offset.y > 0.0 {
             self.offset.x -= 360.0 * delta;
-            if self.offset.x < 0.0 {
-                self.offset.x += Self::GRASS_WIDTH;
+            let width = self.size().x;
+            if self.offset.x < 0.0 && width > 0.0 {
+                self.offset.x = self.offset.x.rem_euclid(width);
             }
             if self.opener.offset() <= 130.0 {
                 self.offset.y -= 60.0 * delta;
@@ -66,13 +78,16 @@ impl<ID, const AS: usize> BattleOpener<ID, AS> for WildBattleOpener {
                 .draw(ctx, Vec2::new(-self.opener.offset, 0.0), LIGHTGRAY);
         }
         self.opener.draw_below_panel(ctx, player, opponent);
-        if self.offset.y > 0.0 {
-            let y = 114.0 - self.offset.y;
-            self.grass
-                .draw(ctx, position(self.offset.x - Self::GRASS_WIDTH, y));
-            self.grass.draw(ctx, position(self.offset.x, y));
-            self.grass
-                .draw(ctx, position(self.offset.x + Self::GRASS_WIDTH, y));
+        if let Some((texture, color)) = &self.overlay {
+            if self.offset.y > 0.0 {
+                let y = 114.0 - self.offset.y;
+                let width = texture.width() as f32;
+                let mut x = self.offset.x - width;
+                while width > 0.0 && x < WIDTH {
+                    texture.draw(ctx, position(x, y).color(*color));
+                    x += width;
+                }
+            }
         }
     }
 
@@ -83,7 +98,7 @@ impl<ID, const AS: usize> BattleOpener<ID, AS> for WildBattleOpener {
 
 impl Reset for WildBattleOpener {
     fn reset(&mut self) {
-        self.offset = Vec2::new(Self::GRASS_WIDTH, Self::GRASS_HEIGHT);
+        self.offset = self.size();
         self.opener.reset();
     }
 }
@@ -0,0 +1,110 @@
+use pokedex::{
+    context::PokedexClientContext,
+    engine::{
+        gui::MessageBox,
+        util::{Completable, Entity, Reset},
+        EngineContext,
+    },
+};
+
+use crate::{
+    catalog::BattleCatalog,
+    options::BattleGuiOptions,
+    ui::{text::{advance, push}, view::{GuiRemotePlayer, TrainerPrize}},
+};
+
+use super::{slide::TrainerSlide, TransitionState};
+
+/// The defeated trainer slides back in, says their defeat dialogue and hands over their prize.
+pub struct BattleTrainerOutro {
+    state: Option<TransitionState>,
+    trainer: TrainerSlide,
+    prize: Option<TrainerPrize>,
+}
+
+impl BattleTrainerOutro {
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            trainer: TrainerSlide::new(),
+            prize: None,
+        }
+    }
+
+    pub fn begin<ID, const AS: usize>(
+        &mut self,
+        ctx: &PokedexClientContext,
+        catalog: &BattleCatalog,
+        player: &str,
+        opponent: &GuiRemotePlayer<ID, AS>,
+        text: &mut MessageBox,
+    ) {
+        self.reset();
+        self.state = Some(TransitionState::Begin);
+        self.trainer.spawn(ctx, opponent.trainer.as_ref());
+
+        text.clear();
+        for page in opponent.dialogue.defeat.iter() {
+            text.push(page.clone());
+        }
+        let prize = &opponent.prize;
+        if prize.money != 0 {
+            push(text, catalog.get("outro.money", &[("player", &player), ("money", &prize.money)]), None);
+        }
+        if let Some(badge) = &prize.badge {
+            push(text, catalog.get("outro.badge", &[("player", &player), ("badge", badge)]), None);
+        }
+        self.prize = Some(prize.clone());
+    }
+
+    pub fn update(&mut self, ctx: &EngineContext, delta: f32, options: &BattleGuiOptions, text: &mut MessageBox) {
+        self.trainer.update(delta);
+        match self.state {
+            Some(TransitionState::Begin) => {
+                if self.trainer.speaking() {
+                    self.state = Some(TransitionState::Run);
+                    text.spawn();
+                }
+            }
+            Some(TransitionState::Run) => match text.finished() {
+                false => advance(ctx, text, options.text_speed, delta),
+                true => {
+                    text.despawn();
+                    self.state = Some(TransitionState::End);
+                }
+            },
+            Some(TransitionState::End) | None => (),
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        self.trainer.draw(ctx);
+    }
+
+    /// If the outro is playing.
+    pub fn alive(&self) -> bool {
+        matches!(
+            self.state,
+            Some(TransitionState::Begin) | Some(TransitionState::Run)
+        )
+    }
+
+    /// The prize awarded by the last outro.
+    pub fn prize(&self) -> Option<&TrainerPrize> {
+        self.prize.as_ref()
+    }
+}
+
+impl Reset for BattleTrainerOutro {
+    fn reset(&mut self) {
+        self.state = None;
+        self.trainer.reset();
+        self.prize = None;
+    }
+}
+
+impl Completable for BattleTrainerOutro {
+    fn finished(&self) -> bool {
+        matches!(self.state, Some(TransitionState::End))
+    }
+}
@@ -0,0 +1,86 @@
+use pokedex::{
+    context::PokedexClientContext,
+    engine::{
+        graphics::draw_o_bottom,
+        tetra::graphics::Texture,
+        util::Reset,
+        EngineContext,
+    },
+    TrainerId,
+};
+
+use super::TransitionState;
+
+/// Slides a trainer in from the right of the screen, such as when they speak during or after a battle.
+pub struct TrainerSlide {
+    state: Option<TransitionState>,
+    texture: Option<Texture>,
+    offset: f32,
+}
+
+impl TrainerSlide {
+    const OFFSET: f32 = 126.0;
+    const SPEED: f32 = 300.0;
+
+    pub fn new() -> Self {
+        Self {
+            state: None,
+            texture: None,
+            offset: Self::OFFSET,
+        }
+    }
+
+    pub fn spawn(&mut self, ctx: &PokedexClientContext, trainer: Option<&TrainerId>) {
+        self.texture = trainer.map(|id| ctx.trainer_textures.get(id).clone());
+        self.offset = Self::OFFSET;
+        self.state = Some(TransitionState::Begin);
+    }
+
+    /// Slides the trainer back out.
+    pub fn leave(&mut self) {
+        self.state = Some(TransitionState::End);
+    }
+
+    pub fn update(&mut self, delta: f32) {
+        match self.state {
+            Some(TransitionState::Begin) => {
+                self.offset -= Self::SPEED * delta;
+                if self.offset < 0.0 {
+                    self.offset = 0.0;
+                    self.state = Some(TransitionState::Run);
+                }
+            }
+            Some(TransitionState::End) => {
+                self.offset += Self::SPEED * delta;
+                if self.offset > Self::OFFSET {
+                    self.offset = Self::OFFSET;
+                    self.state = None;
+                }
+            }
+            Some(TransitionState::Run) | None => (),
+        }
+    }
+
+    /// If the trainer has finished sliding in.
+    pub fn speaking(&self) -> bool {
+        matches!(self.state, Some(TransitionState::Run))
+    }
+
+    pub fn alive(&self) -> bool {
+        self.state.is_some()
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        if self.state.is_some() {
+            draw_o_bottom(ctx, self.texture.as_ref(), 144.0 + self.offset, 74.0);
+        }
+    }
+}
+
+impl Reset for TrainerSlide {
+    fn reset(&mut self) {
+        self.state = None;
+        self.texture = None;
+        self.offset = Self::OFFSET;
+    }
+}
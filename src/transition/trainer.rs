@@ -8,17 +8,55 @@ use pokedex::engine::{
     util::{Reset, WIDTH},
 };
 
+use crate::view::GuiPokemonView;
+
 use super::TransitionState;
 
+/// How a party member is shown on the party bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartyBall {
+    Healthy,
+    Status,
+    Fainted,
+    Empty,
+}
+
+impl PartyBall {
+    const STATUS: Color = Color::rgb(248.0 / 255.0, 176.0 / 255.0, 80.0 / 255.0);
+    const FAINTED: Color = Color::rgb(0.4, 0.4, 0.4);
+
+    pub fn of<'d>(pokemon: &(impl GuiPokemonView<'d> + ?Sized)) -> Self {
+        if pokemon.fainted() {
+            Self::Fainted
+        } else if pokemon.ailment().is_some() {
+            Self::Status
+        } else {
+            Self::Healthy
+        }
+    }
+
+    fn color(&self, opacity: Color) -> Color {
+        let color = match self {
+            Self::Status => Self::STATUS,
+            Self::Fainted => Self::FAINTED,
+            Self::Healthy | Self::Empty => Color::WHITE,
+        };
+        Color::rgba(color.r, color.g, color.b, opacity.a)
+    }
+}
+
 pub struct BattleTrainerPartyIntro {
     state: Option<TransitionState>,
     bar: Texture,
     ball: Texture,
-    player: u8,
-    opponent: u8,
+    player: Vec<PartyBall>,
+    opponent: Vec<PartyBall>,
+    slots: u8,
     counter: u8,
     bar_position: f32,
     ball_position: f32,
+    /// Time left before the bars leave by themselves.
+    hold: Option<f32>,
 }
 
 impl BattleTrainerPartyIntro {
@@ -26,6 +64,9 @@ impl BattleTrainerPartyIntro {
     const BAR_HIDDEN: f32 = 48.0;
     const RIGHT_BALL_POSITION: f32 = 76.0;
     const OPACITY_LEN: f32 = 128.0;
+    const SLOTS: u8 = 6;
+    const BALL_SPACE: f32 = 60.0;
+    const HOLD: f32 = 1.0;
 
     pub fn new(ctx: &mut Context) -> Self {
         Self {
@@ -38,11 +79,13 @@ impl BattleTrainerPartyIntro {
                 ctx,
                 include_bytes!("../../assets/gui/owned.png"),
             ),
-            player: 0,
-            opponent: 0,
+            player: Vec::new(),
+            opponent: Vec::new(),
+            slots: Self::SLOTS,
             counter: 0,
             bar_position: 0.0,
             ball_position: 0.0,
+            hold: None,
         }
     }
 
@@ -64,7 +107,7 @@ impl BattleTrainerPartyIntro {
                             }
                         }
                         false => {
-                            match self.counter == 6 {
+                            match self.counter == self.slots {
                                 true => self.state = Some(TransitionState::Run),
                                 false => self.counter += 1,
                             }
@@ -72,9 +115,20 @@ impl BattleTrainerPartyIntro {
                         }
                     },
                 },
-                TransitionState::Run => (),
+                TransitionState::Run => {
+                    if let Some(hold) = self.hold.as_mut() {
+                        *hold -= delta;
+                        if *hold < 0.0 {
+                            self.hold = None;
+                            self.end();
+                        }
+                    }
+                }
                 TransitionState::End => {
                     self.bar_position += delta * 240.0;
+                    if self.bar_position > Self::OPACITY_LEN {
+                        self.despawn();
+                    }
                 }
             }
         }
@@ -94,32 +148,36 @@ impl BattleTrainerPartyIntro {
             );
 
             let distance = if matches!(self.state, Some(TransitionState::End)) {
-                10 + (self.bar_position / 6.0) as u16
+                self.distance() + self.bar_position / 6.0
             } else {
-                10
+                self.distance()
             };
 
             // Player
 
-            self.draw_gui(
-                ctx,
-                Vec2::new(WIDTH, 96.0),
-                true,
-                self.player,
-                opacity,
-                distance,
-            );
+            if !self.player.is_empty() {
+                self.draw_gui(
+                    ctx,
+                    Vec2::new(WIDTH, 96.0),
+                    true,
+                    &self.player,
+                    opacity,
+                    distance,
+                );
+            }
 
             // Opponent
 
-            self.draw_gui(
-                ctx,
-                Vec2::new(0.0, 41.0),
-                false,
-                self.opponent,
-                opacity,
-                distance,
-            );
+            if !self.opponent.is_empty() {
+                self.draw_gui(
+                    ctx,
+                    Vec2::new(0.0, 41.0),
+                    false,
+                    &self.opponent,
+                    opacity,
+                    distance,
+                );
+            }
         }
     }
 
@@ -128,9 +186,9 @@ impl BattleTrainerPartyIntro {
         ctx: &mut Context,
         pos: Vec2<f32>,
         invert: bool,
-        len: u8,
+        balls: &[PartyBall],
         opacity: Color,
-        distance: u16,
+        distance: f32,
     ) {
         let invert = if invert { -1.0 } else { 1.0 };
 
@@ -156,38 +214,49 @@ impl BattleTrainerPartyIntro {
         );
 
         for i in 0..self.counter {
+            let ball = balls.get(i as usize).copied().unwrap_or(PartyBall::Empty);
             self.ball.draw_region(
                 ctx,
-                Rectangle::new(0.0, if len > i { 0.0 } else { 7.0 }, 7.0, 7.0),
+                Self::region(ball),
                 position(
                     pos.x
                         + (self.bar_position.max(0.0) + Self::RIGHT_BALL_POSITION
-                            - (i as u16 * distance) as f32)
+                            - i as f32 * distance)
                             * invert,
                     pos.y - 9.0,
                 )
-                .color(opacity)
+                .color(ball.color(opacity))
                 .scale(Vec2::new(invert, 1.0)),
             );
         }
 
-        if self.ball_position != 0.0 && self.counter < 6 {
+        if self.ball_position != 0.0 && self.counter < self.slots {
+            let ball = balls.get(self.counter as usize).copied().unwrap_or(PartyBall::Empty);
             self.ball.draw_region(
                 ctx,
-                Rectangle::new(0.0, if len > self.counter { 0.0 } else { 7.0 }, 7.0, 7.0),
+                Self::region(ball),
                 position(
                     pos.x
                         + invert
                             * (Self::RIGHT_BALL_POSITION + self.ball_position
-                                - (self.counter * 10) as f32),
+                                - self.counter as f32 * self.distance()),
                     pos.y - 9.0,
                 )
-                .color(opacity)
+                .color(ball.color(opacity))
                 .scale(Vec2::new(invert, 1.0)),
             );
         }
     }
 
+    fn region(ball: PartyBall) -> Rectangle {
+        Rectangle::new(0.0, if ball == PartyBall::Empty { 7.0 } else { 0.0 }, 7.0, 7.0)
+    }
+
+    /// Space between balls, which shrinks for parties larger than 6.
+    fn distance(&self) -> f32 {
+        (Self::BALL_SPACE / (self.slots.max(2) - 1) as f32).min(10.0)
+    }
+
     pub fn end(&mut self) {
         self.state = Some(TransitionState::End);
     }
@@ -196,13 +265,32 @@ impl BattleTrainerPartyIntro {
         matches!(self.state, Some(TransitionState::End))
     }
 
-    pub fn spawn(&mut self, player: usize, opponent: usize) {
-        self.player = player as u8;
-        self.opponent = opponent as u8;
+    /// Shows the party bars of both sides until [`end`](Self::end) is called.
+    pub fn spawn(&mut self, player: impl IntoIterator<Item = PartyBall>, opponent: impl IntoIterator<Item = PartyBall>) {
+        self.player = player.into_iter().collect();
+        self.opponent = opponent.into_iter().collect();
+        self.hold = None;
+        self.begin();
+    }
+
+    /// Briefly shows the opponent's party bar, such as when a trainer sends out their next pokemon.
+    pub fn spawn_opponent(&mut self, opponent: impl IntoIterator<Item = PartyBall>) {
+        self.player.clear();
+        self.opponent = opponent.into_iter().collect();
+        self.hold = Some(Self::HOLD);
+        self.begin();
+    }
+
+    fn begin(&mut self) {
+        self.slots = self.player.len().max(self.opponent.len()).max(Self::SLOTS as usize).min(u8::MAX as usize) as u8;
         self.state = Some(TransitionState::Begin);
         self.reset();
     }
 
+    pub fn alive(&self) -> bool {
+        self.state.is_some()
+    }
+
     pub fn despawn(&mut self) {
         self.state = None;
     }
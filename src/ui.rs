@@ -6,15 +6,18 @@ use pokedex::engine::{
 
 use crate::context::BattleGuiContext;
 
-use self::{background::BattleBackground, panels::{BattlePanel, level::LevelUpMovePanel}, pokemon::bounce::PlayerBounce};
+use self::{background::BattleBackground, evolution::EvolutionScene, panels::{BattlePanel, level::LevelUpMovePanel, stats::LevelUpStatsPanel}, pokemon::{MoveAnimations, animation::MoveAnimationPlayer, bounce::PlayerBounce}};
 
 use super::transition::{
-    introduction::BattleIntroductionManager, opener::BattleOpenerManager,
+    entry::BattleEntryTransition, introduction::BattleIntroductionManager, opener::BattleOpenerManager,
+    outro::BattleTrainerOutro,
+    slide::TrainerSlide,
     trainer::BattleTrainerPartyIntro,
 };
 // use self::panels::level_up::LevelUpMovePanel;
 
 pub mod background;
+pub mod evolution;
 pub mod exp_bar;
 pub mod panels;
 pub mod pokemon;
@@ -47,7 +50,7 @@ impl BattleGuiPositionIndex {
     }
 }
 
-pub struct BattleGui<'d> {
+pub struct BattleGui<'d, ID: Default, const AS: usize> {
     pub background: BattleBackground,
 
     pub panel: BattlePanel<'d>,
@@ -56,13 +59,21 @@ pub struct BattleGui<'d> {
 
     pub bounce: PlayerBounce,
 
-    pub opener: BattleOpenerManager,
-    pub introduction: BattleIntroductionManager,
+    pub entry: BattleEntryTransition,
+    pub opener: BattleOpenerManager<ID, AS>,
+    pub introduction: BattleIntroductionManager<ID, AS>,
     pub trainer: BattleTrainerPartyIntro,
+    pub outro: BattleTrainerOutro,
+    pub dialogue: TrainerSlide,
     pub level_up: LevelUpMovePanel<'d>,
+    pub level_stats: LevelUpStatsPanel,
+    pub evolution: EvolutionScene<'d>,
+
+    pub moves: MoveAnimations,
+    pub animation: MoveAnimationPlayer,
 }
 
-impl<'d> BattleGui<'d> {
+impl<'d, ID: Default, const AS: usize> BattleGui<'d, ID, AS> {
     pub fn new(ctx: &mut Context, gui: &BattleGuiContext) -> Self {
         Self {
             background: BattleBackground::new(ctx, gui),
@@ -73,10 +84,18 @@ impl<'d> BattleGui<'d> {
 
             bounce: PlayerBounce::new(),
 
-            opener: BattleOpenerManager::new(ctx, gui),
+            entry: BattleEntryTransition::new(),
+            opener: BattleOpenerManager::new(gui),
             introduction: BattleIntroductionManager::new(gui),
 			trainer: BattleTrainerPartyIntro::new(ctx),
+            outro: BattleTrainerOutro::new(),
+            dialogue: TrainerSlide::new(),
             level_up: LevelUpMovePanel::new(),
+            level_stats: LevelUpStatsPanel::new(),
+            evolution: EvolutionScene::new(),
+
+            moves: MoveAnimations::new(),
+            animation: MoveAnimationPlayer::default(),
         }
     }
 
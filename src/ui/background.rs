@@ -1,17 +1,71 @@
+use hashbrown::HashMap;
+
 use pokedex::engine::{
     graphics::{byte_texture, position},
     tetra::{
         Context,
-        graphics::Texture,
+        graphics::{Color, Texture},
     }
 };
 
 use crate::context::BattleGuiContext;
 
+/// Where a battle takes place.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BattleEnvironment {
+    pub terrain: BattleTerrain,
+    pub time: TimeOfDay,
+}
+
+/// Terrains the battle has textures for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BattleTerrain {
+    Grass,
+}
+
+impl Default for BattleTerrain {
+    fn default() -> Self {
+        Self::Grass
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeOfDay {
+    Day,
+    Evening,
+    Night,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self::Day
+    }
+}
+
+impl TimeOfDay {
+    pub fn tint(&self) -> Color {
+        match self {
+            TimeOfDay::Day => Color::WHITE,
+            TimeOfDay::Evening => Color::rgb(1.0, 0.82, 0.7),
+            TimeOfDay::Night => Color::rgb(0.55, 0.6, 0.85),
+        }
+    }
+}
+
+/// Textures drawn for a terrain.
+#[derive(Clone)]
+pub struct TerrainTextures {
+    pub background: Texture,
+    /// The platforms the pokemon stand on.
+    pub ground: Texture,
+    /// Scrolls across the screen during the wild battle opener, such as tall grass or water ripples.
+    pub overlay: Option<Texture>,
+}
+
 pub struct BattleBackground {
 
-	background: Texture,
-	ground: Texture,
+	terrains: HashMap<BattleTerrain, TerrainTextures>,
+	environment: BattleEnvironment,
 	pub panel: Texture,
 
 }
@@ -19,18 +73,49 @@ pub struct BattleBackground {
 impl BattleBackground {
 
     pub fn new(ctx: &mut Context, gui: &BattleGuiContext) -> Self {
-        Self {
+        let mut terrains = HashMap::with_capacity(1);
+        terrains.insert(BattleTerrain::Grass, TerrainTextures {
             background: byte_texture(ctx, include_bytes!("../../assets/background.png")),
             ground: byte_texture(ctx, include_bytes!("../../assets/ground.png")),
+            overlay: Some(byte_texture(ctx, include_bytes!("../../assets/grass.png"))),
+        });
+        Self {
+            terrains,
+            environment: Default::default(),
             panel: gui.panel.clone(),
         }
 
     }
 
+    /// Replaces the textures used for a terrain.
+    pub fn insert(&mut self, terrain: BattleTerrain, textures: TerrainTextures) {
+        self.terrains.insert(terrain, textures);
+    }
+
+    pub fn set(&mut self, environment: BattleEnvironment) {
+        self.environment = environment;
+    }
+
+    pub fn environment(&self) -> &BattleEnvironment {
+        &self.environment
+    }
+
+    /// The textures of the current terrain and the color they are drawn with.
+    pub fn textures(&self) -> (&TerrainTextures, Color) {
+        (&self.terrains[&self.environment.terrain], self.environment.time.tint())
+    }
+
+    /// The overlay of the current terrain and the color it is drawn with.
+    pub fn overlay(&self) -> Option<(&Texture, Color)> {
+        let (textures, color) = self.textures();
+        textures.overlay.as_ref().map(|overlay| (overlay, color))
+    }
+
     pub fn draw(&self, ctx: &mut Context, offset: f32) {
-        self.background.draw(ctx, position(0.0, 1.0));
-        self.ground.draw(ctx, position(113.0 - offset, 50.0));
-		self.ground.draw(ctx, position(offset, 103.0));
+        let (textures, color) = self.textures();
+        textures.background.draw(ctx, position(0.0, 1.0).color(color));
+        textures.ground.draw(ctx, position(113.0 - offset, 50.0).color(color));
+		textures.ground.draw(ctx, position(offset, 103.0).color(color));
     }
 
-}
\ No newline at end of file
+}
@@ -0,0 +1,143 @@
+use pokedex::{
+    context::PokedexClientContext,
+    engine::{
+        audio::sound::Sound,
+        graphics::{draw_rectangle, position},
+        gui::MessageBox,
+        input::{pressed, Control},
+        tetra::graphics::{Color, Texture},
+        util::{Completable, Entity, WIDTH},
+        EngineContext,
+    },
+    pokemon::{owned::OwnedPokemon, Pokemon},
+    texture::PokemonTexture,
+    CRY_ID,
+};
+
+use crate::{catalog::BattleCatalog, options::BattleGuiOptions, sound, ui::text::{advance, push}};
+
+/// Flashes between a pokemon's silhouette and its evolution. B cancels it.
+pub struct EvolutionScene<'d> {
+    state: EvolutionState,
+    name: String,
+    into: Option<&'d Pokemon>,
+    textures: Option<(Texture, Texture)>,
+    counter: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EvolutionState {
+    NotAlive,
+    Text,
+    Flashing,
+    /// Showing the result, then returning whether the pokemon evolved.
+    Result(bool),
+}
+
+impl<'d> EvolutionScene<'d> {
+    const DURATION: f32 = 4.0;
+    const HEIGHT: f32 = 113.0;
+
+    pub fn new() -> Self {
+        Self {
+            state: EvolutionState::NotAlive,
+            name: String::new(),
+            into: None,
+            textures: None,
+            counter: 0.0,
+        }
+    }
+
+    pub fn spawn(&mut self, dex: &PokedexClientContext, catalog: &BattleCatalog, pokemon: &OwnedPokemon<'d>, into: &'d Pokemon, text: &mut MessageBox) {
+        self.state = EvolutionState::Text;
+        self.name = pokemon.name().to_owned();
+        self.into = Some(into);
+        self.textures = Some((
+            dex.pokemon_textures.get(&pokemon.pokemon.id, PokemonTexture::Front).clone(),
+            dex.pokemon_textures.get(&into.id, PokemonTexture::Front).clone(),
+        ));
+        self.counter = 0.0;
+        text.clear();
+        text.spawn();
+        push(text, catalog.get("evolution.start", &[("pokemon", &self.name)]), None);
+    }
+
+    /// Returns whether the pokemon evolved once the scene is over.
+    pub fn update(&mut self, ctx: &EngineContext, delta: f32, options: &BattleGuiOptions, catalog: &BattleCatalog, text: &mut MessageBox) -> Option<bool> {
+        match self.state {
+            EvolutionState::NotAlive => None,
+            EvolutionState::Text => {
+                match text.finished() {
+                    false => advance(ctx, text, options.text_speed, delta),
+                    true => {
+                        text.despawn();
+                        self.state = EvolutionState::Flashing;
+                    }
+                }
+                None
+            }
+            EvolutionState::Flashing => {
+                self.counter += delta;
+                if pressed(ctx, Control::B) {
+                    text.clear();
+                    text.spawn();
+                    push(text, catalog.get("evolution.cancel", &[("pokemon", &self.name)]), None);
+                    self.state = EvolutionState::Result(false);
+                } else if self.counter >= Self::DURATION {
+                    text.clear();
+                    text.spawn();
+                    if let Some(into) = self.into {
+                        sound::play(ctx, &Some(Sound::variant(CRY_ID, Some(into.id))));
+                        push(text, catalog.get("evolution.done", &[("pokemon", &self.name), ("species", &into.name)]), None);
+                    }
+                    self.state = EvolutionState::Result(true);
+                }
+                None
+            }
+            EvolutionState::Result(evolved) => match text.finished() {
+                false => {
+                    advance(ctx, text, options.text_speed, delta);
+                    None
+                }
+                true => {
+                    text.despawn();
+                    self.state = EvolutionState::NotAlive;
+                    Some(evolved)
+                }
+            },
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        if let Some((from, into)) = &self.textures {
+            if self.alive() {
+                draw_rectangle(ctx, 0.0, 0.0, WIDTH, Self::HEIGHT, Color::rgb(0.8, 0.85, 0.95));
+                let (texture, color) = match self.state {
+                    EvolutionState::Flashing => {
+                        // swaps between the two silhouettes faster as the evolution goes on
+                        let progress = self.counter / Self::DURATION;
+                        let swaps = self.counter * (2.0 + 10.0 * progress);
+                        let texture = match swaps as u32 % 2 == 0 {
+                            true => from,
+                            false => into,
+                        };
+                        (texture, Color::BLACK)
+                    }
+                    EvolutionState::Result(true) => (into, Color::WHITE),
+                    _ => (from, Color::WHITE),
+                };
+                let x = (WIDTH - texture.width() as f32) / 2.0;
+                let y = (Self::HEIGHT - texture.height() as f32) / 2.0;
+                texture.draw(ctx, position(x, y).color(color));
+                if self.state == EvolutionState::Flashing {
+                    let alpha = (self.counter * std::f32::consts::PI).sin().abs() * 0.6;
+                    draw_rectangle(ctx, 0.0, 0.0, WIDTH, Self::HEIGHT, Color::rgba(1.0, 1.0, 1.0, alpha));
+                }
+            }
+        }
+    }
+
+    pub fn alive(&self) -> bool {
+        self.state != EvolutionState::NotAlive
+    }
+}
@@ -9,23 +9,36 @@ use pokedex::{
     pokemon::owned::OwnedPokemon,
 };
 
+use crate::{catalog::BattleCatalog, ui::text::measure};
+
 pub struct BattleOptions {
-    buttons: [&'static str; 4],
-    pokemon_do: String,
+    buttons: [String; 4],
+    prompt: Vec<String>,
+    /// Tells the player how to open the stat stages panel.
+    hint: String,
     pub cursor: usize,
 }
 
 impl BattleOptions {
     pub fn new() -> Self {
         Self {
-            buttons: ["FIGHT", "BAG", "POKEMON", "RUN"],
-            pokemon_do: String::new(),
+            buttons: Default::default(),
+            prompt: Vec::new(),
+            hint: String::new(),
             cursor: 0,
         }
     }
 
-    pub fn setup<'d>(&mut self, instance: &OwnedPokemon<'d>) {
-        self.pokemon_do = format!("{} do?", instance.name());
+    pub fn setup<'d>(&mut self, catalog: &BattleCatalog, instance: &OwnedPokemon<'d>) {
+        for (button, variant) in self.buttons.iter_mut().zip(["fight", "bag", "pokemon", "run"].iter()) {
+            *button = catalog.variant("options", variant, &[]);
+        }
+        self.prompt = catalog
+            .get("select.prompt", &[("pokemon", &instance.name())])
+            .lines()
+            .map(str::to_owned)
+            .collect();
+        self.hint = catalog.get("stages.hint", &[]);
     }
 
     pub fn input(&mut self, ctx: &EngineContext) {
@@ -41,10 +54,15 @@ impl BattleOptions {
     }
 
     pub fn draw(&self, ctx: &mut EngineContext) {
+        // the stat stages panel opens over this corner
+        Panel::draw(ctx, 0.0, 0.0, measure(ctx, &0, &self.hint) + 16.0, 15.0);
+        draw_text_left(ctx, &0, &self.hint, TextColor::Black, 8.0, 4.0);
+
         Panel::draw(ctx, 120.0, 113.0, 120.0, 47.0);
 
-        draw_text_left(ctx, &1, "What will", TextColor::White, 11.0, 123.0);
-        draw_text_left(ctx, &1, &self.pokemon_do, TextColor::White, 11.0, 139.0);
+        for (index, line) in self.prompt.iter().enumerate() {
+            draw_text_left(ctx, &1, line, TextColor::White, 11.0, 123.0 + index as f32 * 16.0);
+        }
 
         for (index, string) in self.buttons.iter().enumerate() {
             draw_text_left(
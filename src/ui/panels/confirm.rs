@@ -0,0 +1,83 @@
+use pokedex::engine::{
+    graphics::{draw_cursor, draw_text_left},
+    gui::Panel,
+    input::{pressed, Control},
+    text::TextColor,
+    util::{Entity, Reset},
+    EngineContext,
+};
+
+use crate::catalog::BattleCatalog;
+
+/// A yes or no choice drawn above the message box.
+pub struct ConfirmPanel {
+    alive: bool,
+    pub cursor: usize,
+    labels: [String; 2],
+}
+
+impl ConfirmPanel {
+    const ORIGIN: (f32, f32) = (184.0, 73.0);
+
+    pub fn new() -> Self {
+        Self {
+            alive: false,
+            cursor: 0,
+            labels: Default::default(),
+        }
+    }
+
+    /// Spawns the panel with its choices named from the catalog.
+    pub fn open(&mut self, catalog: &BattleCatalog) {
+        self.labels = [catalog.variant("confirm", "yes", &[]), catalog.variant("confirm", "no", &[])];
+        self.spawn();
+    }
+
+    /// Returns whether the player chose yes, once they choose.
+    pub fn input(&mut self, ctx: &EngineContext) -> Option<bool> {
+        if pressed(ctx, Control::Up) && self.cursor > 0 {
+            self.cursor -= 1;
+        } else if pressed(ctx, Control::Down) && self.cursor < 1 {
+            self.cursor += 1;
+        }
+        if pressed(ctx, Control::A) {
+            Some(self.cursor == 0)
+        } else if pressed(ctx, Control::B) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        if self.alive {
+            let (x, y) = Self::ORIGIN;
+            Panel::draw(ctx, x, y, 56.0, 40.0);
+            for (index, label) in self.labels.iter().enumerate() {
+                draw_text_left(ctx, &1, label, TextColor::Black, x + 16.0, y + 8.0 + index as f32 * 16.0);
+            }
+            draw_cursor(ctx, x + 8.0, y + 11.0 + self.cursor as f32 * 16.0);
+        }
+    }
+}
+
+impl Entity for ConfirmPanel {
+    fn spawn(&mut self) {
+        self.alive = true;
+        self.reset();
+    }
+
+    fn despawn(&mut self) {
+        self.alive = false;
+    }
+
+    fn alive(&self) -> bool {
+        self.alive
+    }
+}
+
+impl Reset for ConfirmPanel {
+    fn reset(&mut self) {
+        self.cursor = 0;
+    }
+}
@@ -1,16 +1,23 @@
 use pokedex::{
     engine::{
+        input::{pressed, Control},
         EngineContext,
         util::Reset,
     },
-    pokemon::owned::OwnedPokemon,
+    pokemon::{owned::OwnedPokemon, Pokemon},
 };
 
+use crate::catalog::BattleCatalog;
+
 use super::{move_info::MoveInfoPanel, moves::MovePanel};
 
 pub struct FightPanel<'d> {
     pub moves: MovePanel<'d>,
     info: MoveInfoPanel,
+    /// The opposing pokemon in each active slot.
+    targets: Vec<Option<&'d Pokemon>>,
+    /// The active slot the move hints are for.
+    target: usize,
 }
 
 impl<'d> FightPanel<'d> {
@@ -18,28 +25,63 @@ impl<'d> FightPanel<'d> {
         Self {
             moves: MovePanel::new(),
             info: MoveInfoPanel::new(),
+            targets: Vec::with_capacity(4),
+            target: 0,
         }
     }
 
-    pub fn user(&mut self, instance: &OwnedPokemon<'d>) {
+    pub fn user(
+        &mut self,
+        catalog: &BattleCatalog,
+        instance: &OwnedPokemon<'d>,
+        targets: Vec<Option<&'d Pokemon>>,
+        target: usize,
+    ) {
+        self.targets = targets;
+        self.target = target;
         self.moves.update_names(instance);
-        self.update_move(instance);
+        self.update_move(catalog, instance);
+    }
+
+    /// Shows the move hints against the opposing pokemon in another active slot.
+    pub fn select_target(&mut self, catalog: &BattleCatalog, pokemon: &OwnedPokemon<'d>, target: usize) {
+        if self.target != target {
+            self.target = target;
+            self.update_move(catalog, pokemon);
+        }
     }
 
-    pub fn update_move(&mut self, pokemon: &OwnedPokemon<'d>) {
+    /// The selected opposing pokemon, or the only one left if the selected slot is empty.
+    fn target(&self) -> Option<&'d Pokemon> {
+        self.targets.get(self.target).copied().flatten().or_else(|| {
+            let mut targets = self.targets.iter().flatten();
+            match (targets.next(), targets.next()) {
+                (Some(target), None) => Some(*target),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn update_move(&mut self, catalog: &BattleCatalog, pokemon: &OwnedPokemon<'d>) {
         if let Some(pmove) = pokemon.moves.get(self.moves.cursor) {
-            self.info.update_move(pmove);
+            self.info.update_move(catalog, pmove, self.target());
         }
     }
 
     pub fn draw(&self, ctx: &mut EngineContext) {
-        self.moves.draw(ctx);
+        match self.info.detailed {
+            true => self.info.draw_details(ctx),
+            false => self.moves.draw(ctx),
+        }
         self.info.draw(ctx);
     }
 
-    pub fn input(&mut self, ctx: &EngineContext, pokemon: &OwnedPokemon<'d>) {
+    pub fn input(&mut self, ctx: &EngineContext, catalog: &BattleCatalog, pokemon: &OwnedPokemon<'d>) {
+        if pressed(ctx, Control::Select) {
+            self.info.detailed = !self.info.detailed;
+        }
         if self.moves.input(ctx) {
-            self.update_move(pokemon);
+            self.update_move(catalog, pokemon);
         }
     }
 }
@@ -49,5 +91,6 @@ impl<'d> Reset for FightPanel<'d> {
         if self.moves.cursor >= self.moves.names.len() {
             self.moves.cursor = 0;
         }
+        self.info.detailed = false;
     }
 }
@@ -2,90 +2,176 @@ use pokedex::{
     engine::{
         gui::MessageBox,
         input::{pressed, Control},
-        text::{MessagePage, TextColor},
-        util::{Completable, Entity},
+        text::TextColor,
+        util::{Completable, Entity, Reset},
         EngineContext,
     },
     moves::Move,
     pokemon::owned::OwnedPokemon,
 };
 
-use super::moves::MovePanel;
+use crate::{catalog::BattleCatalog, options::TextSpeed, ui::text::{advance, push}};
+
+use super::{confirm::ConfirmPanel, move_info::MoveInfoPanel, moves::MovePanel};
 
 pub struct LevelUpMovePanel<'d> {
-    
+
     state: LevelUpState,
 
     move_panel: MovePanel<'d>,
+    info: MoveInfoPanel,
+    confirm: ConfirmPanel,
 
     moves: Vec<&'d Move>,
 }
 
 enum LevelUpState {
     NotAlive,
-    Text,
+    /// Picks what to do with the next move.
+    Next,
+    /// Shows text, then goes to the next state.
+    Text(LevelUpPrompt),
+    /// "Delete a move to make room?"
+    Delete,
+    /// "Stop learning the move?"
+    Stop,
     Moves,
 }
 
+#[derive(Clone, Copy)]
+enum LevelUpPrompt {
+    Next,
+    Delete,
+    Stop,
+}
+
 impl<'d> LevelUpMovePanel<'d> {
     pub fn new() -> Self {
         Self {
             state: LevelUpState::NotAlive,
             move_panel: MovePanel::new(),
+            info: MoveInfoPanel::new(),
+            confirm: ConfirmPanel::new(),
             moves: Vec::new(),
         }
     }
 
     pub fn spawn(&mut self, instance: &OwnedPokemon<'d>, text: &mut MessageBox, moves: Vec<&'d Move>) {
-        self.state = LevelUpState::Text;
+        self.state = LevelUpState::Next;
         self.moves = moves;
+        self.move_panel.names = [None; 4];
         self.move_panel.update_names(instance);
+        self.move_panel.reset();
         text.despawn();
     }
 
-    pub fn update(&mut self, ctx: &EngineContext, text: &mut MessageBox, delta: f32, pokemon: &mut OwnedPokemon<'d>) -> Option<(usize, &'d Move)> {
+    /// Returns the move slot and move once a move is learned.
+    pub fn update(&mut self, ctx: &EngineContext, text: &mut MessageBox, catalog: &BattleCatalog, speed: TextSpeed, delta: f32, pokemon: &mut OwnedPokemon<'d>) -> Option<(usize, &'d Move)> {
         match self.state {
-            LevelUpState::Text => {
-                match text.alive() {
-                    true => {
-                        text.update(ctx, delta);
-                        if text.finished() {
-                            self.state = LevelUpState::Moves;
-                            text.despawn();
-                        }
-                        None
-                    },
-                    false => match self.moves.first() {
-                        Some(move_ref) => {
-                            text.spawn();
-                            text.push(MessagePage {
-                                lines: vec![
-                                    format!("{} is trying to", pokemon.name()),
-                                    format!("learn {}", move_ref.name),
-                                ],
-                                wait: None,
-                            });
-                            self.update(ctx, text, delta, pokemon)
+            LevelUpState::Next => match self.moves.first().copied() {
+                Some(move_ref) => {
+                    text.clear();
+                    text.spawn();
+                    let slot = pokemon.moves.len();
+                    match slot < self.move_panel.names.len() {
+                        true => {
+                            self.moves.remove(0);
+                            pokemon.moves.add(None, &move_ref.id);
+                            self.move_panel.names[slot] = Some((move_ref, TextColor::Black));
+                            push(text, catalog.get("learn.learned", &[("pokemon", &pokemon.name()), ("move", &move_ref.name)]), None);
+                            self.state = LevelUpState::Text(LevelUpPrompt::Next);
+                            Some((slot, move_ref))
                         }
-                        None => {
-                            self.state = LevelUpState::NotAlive;
+                        false => {
+                            push(text, catalog.get("learn.trying", &[("pokemon", &pokemon.name()), ("move", &move_ref.name)]), None);
+                            push(text, catalog.get("learn.full", &[("pokemon", &pokemon.name())]), None);
+                            Self::delete_prompt(text, catalog, move_ref);
+                            self.state = LevelUpState::Text(LevelUpPrompt::Delete);
                             None
                         }
                     }
                 }
+                None => {
+                    text.despawn();
+                    self.state = LevelUpState::NotAlive;
+                    None
+                }
             },
+            LevelUpState::Text(prompt) => {
+                match text.finished() {
+                    false => advance(ctx, text, speed, delta),
+                    true => match prompt {
+                        LevelUpPrompt::Next => self.state = LevelUpState::Next,
+                        LevelUpPrompt::Delete => {
+                            self.confirm.open(catalog);
+                            self.state = LevelUpState::Delete;
+                        }
+                        LevelUpPrompt::Stop => {
+                            self.confirm.open(catalog);
+                            self.state = LevelUpState::Stop;
+                        }
+                    },
+                }
+                None
+            }
+            LevelUpState::Delete => {
+                if let Some(yes) = self.confirm.input(ctx) {
+                    self.confirm.despawn();
+                    match yes {
+                        true => {
+                            text.despawn();
+                            self.info.detailed = false;
+                            self.update_info(catalog, pokemon);
+                            self.state = LevelUpState::Moves;
+                        }
+                        false => self.stop_prompt(text, catalog),
+                    }
+                }
+                None
+            }
+            LevelUpState::Stop => {
+                if let Some(yes) = self.confirm.input(ctx) {
+                    self.confirm.despawn();
+                    text.clear();
+                    text.spawn();
+                    match yes {
+                        true => {
+                            let move_ref = self.moves.remove(0);
+                            push(text, catalog.get("learn.skipped", &[("pokemon", &pokemon.name()), ("move", &move_ref.name)]), None);
+                            self.state = LevelUpState::Text(LevelUpPrompt::Next);
+                        }
+                        false => {
+                            Self::delete_prompt(text, catalog, self.moves[0]);
+                            self.state = LevelUpState::Text(LevelUpPrompt::Delete);
+                        }
+                    }
+                }
+                None
+            }
             LevelUpState::Moves => {
-                self.move_panel.input(ctx);
-                let a = pressed(ctx, Control::A);
-                if pressed(ctx, Control::B) || a {
-                    self.state = LevelUpState::Text;
-                    let pokemon_move = self.moves.remove(0);
-                    if a {
-                        self.move_panel.names[self.move_panel.cursor] =
-                        Some((pokemon_move, TextColor::Black));
-                        pokemon.moves.add(Some(self.move_panel.cursor), &pokemon_move.id);
-                        return Some((self.move_panel.cursor, pokemon_move));
+                if pressed(ctx, Control::Select) {
+                    self.info.detailed = !self.info.detailed;
+                }
+                if self.move_panel.input(ctx) {
+                    self.update_info(catalog, pokemon);
+                }
+                if pressed(ctx, Control::A) {
+                    let cursor = self.move_panel.cursor;
+                    if let Some((forgotten, ..)) = self.move_panel.names[cursor] {
+                        let move_ref = self.moves.remove(0);
+                        self.move_panel.names[cursor] = Some((move_ref, TextColor::Black));
+                        pokemon.moves.add(Some(cursor), &move_ref.id);
+                        text.clear();
+                        text.spawn();
+                        push(text, catalog.get("learn.poof", &[]), None);
+                        push(text, catalog.get("learn.forgot", &[("pokemon", &pokemon.name()), ("move", &forgotten.name)]), None);
+                        push(text, catalog.get("learn.and", &[]), Some(0.5));
+                        push(text, catalog.get("learn.learned", &[("pokemon", &pokemon.name()), ("move", &move_ref.name)]), None);
+                        self.state = LevelUpState::Text(LevelUpPrompt::Next);
+                        return Some((cursor, move_ref));
                     }
+                } else if pressed(ctx, Control::B) {
+                    self.stop_prompt(text, catalog);
                 }
                 None
             },
@@ -93,10 +179,36 @@ impl<'d> LevelUpMovePanel<'d> {
         }
     }
 
+    fn delete_prompt(text: &mut MessageBox, catalog: &BattleCatalog, move_ref: &Move) {
+        push(text, catalog.get("learn.delete", &[("move", &move_ref.name)]), None);
+    }
+
+    fn stop_prompt(&mut self, text: &mut MessageBox, catalog: &BattleCatalog) {
+        text.clear();
+        text.spawn();
+        if let Some(move_ref) = self.moves.first() {
+            push(text, catalog.get("learn.stop", &[("move", &move_ref.name)]), None);
+        }
+        self.state = LevelUpState::Text(LevelUpPrompt::Stop);
+    }
+
+    fn update_info(&mut self, catalog: &BattleCatalog, pokemon: &OwnedPokemon<'d>) {
+        if let Some(instance) = pokemon.moves.get(self.move_panel.cursor) {
+            self.info.update_move(catalog, instance, None);
+        }
+    }
+
     pub fn draw(&self, ctx: &mut EngineContext) {
         match self.state {
-            LevelUpState::Moves => self.move_panel.draw(ctx),
-            LevelUpState::Text | LevelUpState::NotAlive => (),
+            LevelUpState::Moves => {
+                match self.info.detailed {
+                    true => self.info.draw_details(ctx),
+                    false => self.move_panel.draw(ctx),
+                }
+                self.info.draw(ctx);
+            }
+            LevelUpState::Delete | LevelUpState::Stop => self.confirm.draw(ctx),
+            LevelUpState::Text(..) | LevelUpState::Next | LevelUpState::NotAlive => (),
         }
     }
 
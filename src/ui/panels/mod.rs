@@ -7,15 +7,18 @@ use pokedex::{
     },
     item::ItemId,
     moves::MoveTarget,
-    pokemon::owned::OwnedPokemon,
+    pokemon::{owned::OwnedPokemon, Pokemon},
 };
 
-use crate::view::GuiPokemonView;
+use crate::{catalog::BattleCatalog, view::GuiPokemonView};
 
-use self::{battle::BattleOptions, fight::FightPanel, target::TargetPanel};
+use self::{battle::BattleOptions, fight::FightPanel, stages::StatStagesPanel, target::TargetPanel};
 
+pub mod confirm;
 pub mod move_info;
 pub mod moves;
+pub mod stages;
+pub mod stats;
 pub mod target;
 
 pub mod battle;
@@ -31,12 +34,14 @@ pub struct BattlePanel<'d> {
     pub battle: BattleOptions,
     pub fight: FightPanel<'d>,
     pub targets: TargetPanel,
+    pub stages: StatStagesPanel,
 }
 
 pub enum BattlePanels {
     Main,
     Fight,
     Target(MoveTarget, Option<ItemId>),
+    Stages,
 }
 
 impl Default for BattlePanels {
@@ -53,30 +58,40 @@ impl<'d> BattlePanel<'d> {
             battle: BattleOptions::new(),
             fight: FightPanel::new(),
             targets: TargetPanel::new(),
+            stages: StatStagesPanel::new(),
         }
     }
 
-    pub fn user(&mut self, instance: &OwnedPokemon<'d>) {
-        self.battle.setup(instance);
-        self.fight.user(instance);
+    /// Sets up the panels for a pokemon, with the opposing pokemon in each active slot as its targets.
+    /// The target cursor is kept between turns, so hints stay on the last target chosen.
+    pub fn user(&mut self, catalog: &BattleCatalog, instance: &OwnedPokemon<'d>, targets: Vec<Option<&'d Pokemon>>) {
+        if self.targets.cursor >= targets.len() {
+            self.targets.cursor = 0;
+        }
+        self.battle.setup(catalog, instance);
+        self.fight.user(catalog, instance, targets, self.targets.cursor);
         self.battle.cursor = 0;
         self.fight.moves.cursor = 0;
-        self.targets.cursor = 0;
         self.spawn();
     }
 
-    pub fn target<ID, P: GuiPokemonView<'d>, const AS: usize>(&mut self, targets: &PlayerParty<ID, usize, P, AS>) {
-        self.targets.update_names(targets);
+    pub fn target<ID, P: GuiPokemonView<'d>, const AS: usize>(&mut self, catalog: &BattleCatalog, targets: &PlayerParty<ID, usize, P, AS>) {
+        self.targets.update_names(catalog, targets);
     }
 
     pub fn input(
         &mut self,
         ctx: &EngineContext,
+        catalog: &BattleCatalog,
         pokemon: &OwnedPokemon<'d>,
     ) -> Option<BattlePanels> {
         if self.alive {
             match self.active {
                 BattlePanels::Main => {
+                    if pressed(ctx, Control::Start) {
+                        self.active = BattlePanels::Stages;
+                        return Some(BattlePanels::Stages);
+                    }
                     self.battle.input(ctx);
                     pressed(ctx, Control::A).then(|| BattlePanels::Main)
                 }
@@ -84,16 +99,27 @@ impl<'d> BattlePanel<'d> {
                     if pressed(ctx, Control::B) {
                         self.active = BattlePanels::Main;
                     }
-                    self.fight.input(ctx, pokemon);
+                    self.fight.input(ctx, catalog, pokemon);
                     pressed(ctx, Control::A).then(|| BattlePanels::Fight)
                 }
-                BattlePanels::Target(..) => {
+                BattlePanels::Target(ref target, ref item) => {
+                    let opponent = item.is_none() && matches!(target, MoveTarget::Opponent | MoveTarget::Any);
                     if pressed(ctx, Control::B) {
                         self.active = BattlePanels::Fight;
                     }
                     self.targets.input(ctx);
+                    if opponent {
+                        self.fight.select_target(catalog, pokemon, self.targets.cursor);
+                    }
                     pressed(ctx, Control::A).then(|| std::mem::take(&mut self.active))
                 }
+                BattlePanels::Stages => {
+                    if pressed(ctx, Control::B) {
+                        self.active = BattlePanels::Main;
+                    }
+                    self.stages.input(ctx);
+                    None
+                }
             }
         } else {
             None
@@ -106,6 +132,7 @@ impl<'d> BattlePanel<'d> {
                 BattlePanels::Main => self.battle.draw(ctx),
                 BattlePanels::Fight => self.fight.draw(ctx),
                 BattlePanels::Target(..) => self.targets.draw(ctx),
+                BattlePanels::Stages => self.stages.draw(ctx),
             }
         }
     }
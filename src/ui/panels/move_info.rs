@@ -5,33 +5,114 @@ use pokedex::{
         text::TextColor,
         EngineContext,
     },
-    moves::owned::OwnedMove,
+    moves::{owned::OwnedMove, MoveCategory},
+    pokemon::Pokemon,
+    types::Effective,
 };
 
+use crate::{catalog::BattleCatalog, ui::text::{measure, wrap}};
+
 pub struct MoveInfoPanel {
+    pp_label: String,
     pp: String,
     move_type: String,
+    pub detailed: bool,
+    details: MoveDetails,
+}
+
+#[derive(Default)]
+struct MoveDetails {
+    description: Option<String>,
+    category: String,
+    power: String,
+    accuracy: String,
+    priority: String,
+    effective: Option<String>,
 }
 
 impl MoveInfoPanel {
     // const ORIGIN: Vec2 = const_vec2!([160.0, 113.0]);
 
+    /// Width of the move description's text.
+    const DESCRIPTION_WIDTH: f32 = 224.0;
+    const DESCRIPTION_LINES: usize = 2;
+
     pub fn new() -> Self {
         Self {
+            pp_label: String::from("PP"),
             pp: String::from("x/y"),
             move_type: String::from("TYPE/"),
+            detailed: false,
+            details: Default::default(),
         }
     }
 
-    pub fn update_move<'d>(&mut self, instance: &OwnedMove<'d>) {
+    pub fn update_move<'d>(&mut self, catalog: &BattleCatalog, instance: &OwnedMove<'d>, target: Option<&Pokemon>) {
         let move_ref = instance.0;
+        self.pp_label = catalog.get("move_info.pp", &[]);
         self.pp = format!("{}/{}", instance.uses(), move_ref.pp);
-        self.move_type = format!("TYPE/{:?}", move_ref.pokemon_type);
+        let move_type = catalog.name("type.names", &format!("{:?}", move_ref.pokemon_type));
+        self.move_type = catalog.get("move_info.type", &[("type", &move_type)]);
+
+        self.details.description = catalog.find("move.descriptions", &move_ref.id.to_string());
+        self.details.category = catalog.variant(
+            "move_info.category",
+            match move_ref.category {
+                MoveCategory::Physical => "physical",
+                MoveCategory::Special => "special",
+                MoveCategory::Status => "status",
+            },
+            &[],
+        );
+        self.details.power = match move_ref.power {
+            Some(power) => catalog.variant("move_info.power", "some", &[("power", &power)]),
+            None => catalog.variant("move_info.power", "none", &[]),
+        };
+        self.details.accuracy = match move_ref.accuracy {
+            Some(accuracy) => catalog.variant("move_info.accuracy", "some", &[("accuracy", &accuracy)]),
+            None => catalog.variant("move_info.accuracy", "none", &[]),
+        };
+        self.details.priority = catalog.get("move_info.priority", &[("priority", &format!("{:+}", move_ref.priority))]);
+        self.details.effective = match move_ref.category {
+            MoveCategory::Status => None,
+            category => target
+                .map(|target| Self::hint(catalog, target.effective(move_ref.pokemon_type, category)))
+                .flatten(),
+        };
+    }
+
+    fn hint(catalog: &BattleCatalog, effective: Effective) -> Option<String> {
+        let variant = match effective {
+            Effective::Ineffective => "ineffective",
+            Effective::NotEffective => "not",
+            Effective::Effective => return None,
+            Effective::SuperEffective => "super",
+        };
+        Some(catalog.variant("move_info.hint", variant, &[]))
+    }
+
+    /// Draws the detailed view over the move list, with the move's description above it.
+    pub fn draw_details(&self, ctx: &mut EngineContext) {
+        if let Some(description) = self.details.description.as_ref() {
+            Panel::draw(ctx, 0.0, 79.0, 240.0, 34.0);
+            let lines = wrap(description, Self::DESCRIPTION_WIDTH, |text| measure(ctx, &0, text));
+            for (index, line) in lines.iter().take(Self::DESCRIPTION_LINES).enumerate() {
+                draw_text_left(ctx, &0, line, TextColor::Black, 8.0, 86.0 + index as f32 * 13.0);
+            }
+        }
+        Panel::draw(ctx, 0.0, 113.0, 160.0, 47.0);
+        draw_text_left(ctx, &0, &self.details.category, TextColor::Black, 16.0, 120.0);
+        draw_text_right(ctx, &0, &self.details.power, TextColor::Black, 152.0, 120.0);
+        draw_text_left(ctx, &0, &self.details.accuracy, TextColor::Black, 16.0, 133.0);
+        draw_text_right(ctx, &0, &self.details.priority, TextColor::Black, 152.0, 133.0);
+        if let Some(effective) = self.details.effective.as_ref() {
+            draw_text_left(ctx, &0, effective, TextColor::Red, 16.0, 146.0);
+        }
     }
 
     pub fn draw(&self, ctx: &mut EngineContext) {
         Panel::draw(ctx, 160.0, 113.0, 80.0, 47.0);
-        draw_text_left(ctx, &0, "PP", TextColor::Black, 168.0, 124.0);
+        draw_text_left(ctx, &0, &self.pp_label, TextColor::Black, 168.0, 124.0);
         draw_text_left(ctx, &0, &self.move_type, TextColor::Black, 168.0, 140.0);
         draw_text_right(ctx, &0, &self.pp, TextColor::Black, 232.0, 124.0);
     }
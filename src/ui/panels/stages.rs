@@ -0,0 +1,87 @@
+use pokedex::engine::{
+    graphics::{draw_text_left, draw_text_right},
+    gui::Panel,
+    input::{pressed, Control},
+    text::TextColor,
+    EngineContext,
+};
+
+use crate::{catalog::BattleCatalog, ui::pokemon::stages::StatStages};
+
+/// Lists the stat stage modifiers of every pokemon on the field.
+/// Lists longer than the screen are split into pages.
+pub struct StatStagesPanel {
+    lines: Vec<String>,
+    page: usize,
+}
+
+impl StatStagesPanel {
+    const LINE: f32 = 14.0;
+    /// Lines that fit on the screen at once.
+    const LINES: usize = 10;
+    const PADDING: f32 = 9.0;
+
+    pub fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            page: 0,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.page = 0;
+    }
+
+    pub fn add(&mut self, catalog: &BattleCatalog, name: &str, stages: &StatStages) {
+        self.lines.push(name.to_owned());
+        match stages.stages.is_empty() {
+            true => self.lines.push(format!("  {}", catalog.get("stat.unchanged", &[]))),
+            false => {
+                for (stat, stage) in stages.stages.iter() {
+                    let stat = catalog.name("stat.names", &stat.to_string());
+                    self.lines.push(format!("  {} {:+}", stat, stage));
+                }
+            }
+        }
+    }
+
+    fn pages(&self) -> usize {
+        ((self.lines.len() + Self::LINES - 1) / Self::LINES).max(1)
+    }
+
+    /// Turns the page.
+    pub fn input(&mut self, ctx: &EngineContext) {
+        if (pressed(ctx, Control::Right) || pressed(ctx, Control::Down)) && self.page + 1 < self.pages() {
+            self.page += 1;
+        } else if (pressed(ctx, Control::Left) || pressed(ctx, Control::Up)) && self.page > 0 {
+            self.page -= 1;
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        let lines = &self.lines[(self.page * Self::LINES).min(self.lines.len())..];
+        let lines = &lines[..lines.len().min(Self::LINES)];
+        Panel::draw(ctx, 0.0, 0.0, 240.0, Self::PADDING * 2.0 + lines.len() as f32 * Self::LINE);
+        if self.pages() > 1 {
+            draw_text_right(
+                ctx,
+                &0,
+                &format!("{}/{}", self.page + 1, self.pages()),
+                TextColor::Black,
+                229.0,
+                Self::PADDING,
+            );
+        }
+        for (index, line) in lines.iter().enumerate() {
+            draw_text_left(
+                ctx,
+                &0,
+                line,
+                TextColor::Black,
+                11.0,
+                Self::PADDING + index as f32 * Self::LINE,
+            );
+        }
+    }
+}
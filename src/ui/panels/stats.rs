@@ -0,0 +1,112 @@
+use pokedex::{
+    engine::{
+        graphics::{draw_text_left, draw_text_right},
+        gui::Panel,
+        input::{pressed, Control},
+        text::TextColor,
+        util::{Entity, Reset},
+        EngineContext,
+    },
+    pokemon::{owned::OwnedPokemon, stat::BaseStat, Level, Pokemon},
+};
+
+use crate::catalog::BattleCatalog;
+
+/// Shows the stats a pokemon gained on a level up, then its new stats.
+pub struct LevelUpStatsPanel {
+    alive: bool,
+    totals: bool,
+    names: [String; 6],
+    gains: [String; 6],
+    stats: [String; 6],
+}
+
+impl LevelUpStatsPanel {
+    const ORIGIN: (f32, f32) = (136.0, 1.0);
+    /// Keys of the stat names in the catalog, in the order the stats are shown.
+    const NAMES: [&'static str; 6] = ["hp", "attack", "defense", "sp_attack", "sp_defense", "speed"];
+
+    pub fn new() -> Self {
+        Self {
+            alive: false,
+            totals: false,
+            names: Default::default(),
+            gains: Default::default(),
+            stats: Default::default(),
+        }
+    }
+
+    /// Spawns the panel with the stats gained from the previous level.
+    pub fn spawn(&mut self, catalog: &BattleCatalog, pokemon: &OwnedPokemon, level: Level) {
+        for (name, key) in self.names.iter_mut().zip(Self::NAMES.iter()) {
+            *name = catalog.name("level_stats.names", key);
+        }
+        let previous = Self::stats(pokemon, level.saturating_sub(1));
+        let current = Self::stats(pokemon, level);
+        for i in 0..current.len() {
+            self.gains[i] = format!("+{}", current[i].saturating_sub(previous[i]));
+            self.stats[i] = current[i].to_string();
+        }
+        Entity::spawn(self);
+    }
+
+    fn stats(pokemon: &OwnedPokemon, level: Level) -> [BaseStat; 6] {
+        let base = &pokemon.pokemon.base;
+        let (ivs, evs) = (&pokemon.ivs, &pokemon.evs);
+        [
+            Pokemon::base_hp(base.hp, ivs.hp, evs.hp, level),
+            Pokemon::base_stat(base.atk, ivs.atk, evs.atk, level),
+            Pokemon::base_stat(base.def, ivs.def, evs.def, level),
+            Pokemon::base_stat(base.sp_atk, ivs.sp_atk, evs.sp_atk, level),
+            Pokemon::base_stat(base.sp_def, ivs.sp_def, evs.sp_def, level),
+            Pokemon::base_stat(base.speed, ivs.speed, evs.speed, level),
+        ]
+    }
+
+    /// Moves from the gains to the totals, then closes the panel.
+    pub fn input(&mut self, ctx: &EngineContext) {
+        if pressed(ctx, Control::A) || pressed(ctx, Control::B) {
+            match self.totals {
+                false => self.totals = true,
+                true => self.despawn(),
+            }
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        if self.alive {
+            let (x, y) = Self::ORIGIN;
+            Panel::draw(ctx, x, y, 104.0, 104.0);
+            let values = match self.totals {
+                true => &self.stats,
+                false => &self.gains,
+            };
+            for (i, (name, value)) in self.names.iter().zip(values.iter()).enumerate() {
+                let y = y + 8.0 + i as f32 * 15.0;
+                draw_text_left(ctx, &1, name, TextColor::Black, x + 8.0, y);
+                draw_text_right(ctx, &1, value, TextColor::Black, x + 96.0, y);
+            }
+        }
+    }
+}
+
+impl Entity for LevelUpStatsPanel {
+    fn spawn(&mut self) {
+        self.alive = true;
+        self.reset();
+    }
+
+    fn despawn(&mut self) {
+        self.alive = false;
+    }
+
+    fn alive(&self) -> bool {
+        self.alive
+    }
+}
+
+impl Reset for LevelUpStatsPanel {
+    fn reset(&mut self) {
+        self.totals = false;
+    }
+}
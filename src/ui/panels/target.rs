@@ -8,10 +8,10 @@ use pokedex::engine::{
     EngineContext,
 };
 
-use crate::view::GuiPokemonView;
+use crate::{catalog::BattleCatalog, view::GuiPokemonView};
 
 pub struct TargetPanel {
-    pub names: Vec<Option<String>>,
+    pub names: Vec<String>,
     pub cursor: usize,
 }
 
@@ -23,12 +23,20 @@ impl TargetPanel {
         }
     }
 
-    pub fn update_names<'d, ID, P: GuiPokemonView<'d>, const AS: usize>(&mut self, targets: &PlayerParty<ID, usize, P, AS>) {
+    pub fn update_names<'d, ID, P: GuiPokemonView<'d>, const AS: usize>(
+        &mut self,
+        catalog: &BattleCatalog,
+        targets: &PlayerParty<ID, usize, P, AS>,
+    ) {
         self.names.clear();
         self.names.extend(targets.active.iter().map(|i| {
             i.map(|index| targets.pokemon.get(index))
                 .flatten()
-                .map(|p| p.name().to_owned())
+                .map(|p| match p.revealed() {
+                    true => p.name().to_owned(),
+                    false => catalog.get("name.unknown", &[]),
+                })
+                .unwrap_or_else(|| catalog.get("target.empty", &[]))
         }));
     }
 
@@ -55,7 +63,7 @@ impl TargetPanel {
             draw_text_left(
                 ctx,
                 &0,
-                name.as_ref().map(|name| name.as_str()).unwrap_or("None"),
+                name,
                 TextColor::Black,
                 16.0 + x_offset,
                 121.0 + y_offset,
@@ -0,0 +1,79 @@
+use pokedex::{
+    ailment::Ailment,
+    engine::{
+        graphics::{draw_rectangle, draw_text_left},
+        tetra::{graphics::Color, math::Vec2},
+        text::TextColor,
+        EngineContext,
+    },
+};
+
+/// Looping sprite effect for a pokemon's current ailment.
+#[derive(Default)]
+pub struct AilmentEffect {
+    pub ailment: Option<Ailment>,
+    accumulator: f32,
+}
+
+impl AilmentEffect {
+    pub const LENGTH: f32 = 1.5;
+
+    const POISON: Color = Color::rgb(160.0 / 255.0, 64.0 / 255.0, 160.0 / 255.0);
+    const SPARK: Color = Color::rgb(248.0 / 255.0, 208.0 / 255.0, 48.0 / 255.0);
+
+    pub fn update(&mut self, delta: f32, ailment: Option<Ailment>) {
+        if self.ailment != ailment {
+            self.ailment = ailment;
+            self.accumulator = 0.0;
+        }
+        if self.ailment.is_some() {
+            self.accumulator += delta;
+            if self.accumulator > Self::LENGTH {
+                self.accumulator -= Self::LENGTH;
+            }
+        }
+    }
+
+    /// Draws the effect over a sprite whose bottom left corner is at `origin`.
+    pub fn draw(&self, ctx: &mut EngineContext, origin: Vec2<f32>, width: f32, height: f32) {
+        let progress = self.accumulator / Self::LENGTH;
+        match self.ailment {
+            Some(Ailment::Poison) => {
+                for i in 0..3 {
+                    let offset = (progress + i as f32 / 3.0) % 1.0;
+                    draw_rectangle(
+                        ctx,
+                        origin.x + width * (i as f32 + 1.0) / 4.0,
+                        origin.y - height * offset,
+                        3.0,
+                        3.0,
+                        Self::POISON,
+                    );
+                }
+            }
+            Some(Ailment::Sleep) => {
+                for i in 0..2 {
+                    let offset = (progress + i as f32 / 2.0) % 1.0;
+                    draw_text_left(
+                        ctx,
+                        &0,
+                        "Z",
+                        TextColor::White,
+                        origin.x + width * 0.75 + offset * 12.0,
+                        origin.y - height - offset * 16.0,
+                    );
+                }
+            }
+            Some(Ailment::Paralysis) => {
+                if progress % 0.25 < 0.125 {
+                    let x = origin.x + width * if progress < 0.5 { 0.25 } else { 0.65 };
+                    let y = origin.y - height * 0.6;
+                    draw_rectangle(ctx, x, y, 2.0, 6.0, Self::SPARK);
+                    draw_rectangle(ctx, x + 2.0, y + 4.0, 4.0, 2.0, Self::SPARK);
+                    draw_rectangle(ctx, x + 4.0, y + 6.0, 2.0, 6.0, Self::SPARK);
+                }
+            }
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Move animations loaded from RON files at runtime.
+//!
+//! Each file in the animation directory is named after the move it animates
+//! (`tackle.ron`) and holds a [`MoveAnimation`]:
+//!
+//! ```ron
+//! MoveAnimation(
+//!     lunge: Some(12.0),
+//!     textures: { "hit": "hit.png" },
+//!     timeline: [
+//!         (time: 0.0, event: Sound("hit")),
+//!         (time: 0.0, event: Sprite(
+//!             texture: "hit",
+//!             at: (anchor: Target),
+//!             duration: 0.3,
+//!             tweens: [(property: Scale(2.0), duration: 0.3), (property: Opacity(0.0), delay: 0.15, duration: 0.15)],
+//!         )),
+//!         (time: 0.1, event: Shake(intensity: 2.0, duration: 0.2)),
+//!         (time: 0.1, event: Flash(color: (1.0, 1.0, 1.0), duration: 0.1)),
+//!     ],
+//! )
+//! ```
+//!
+//! Coordinates are relative to an anchor and are mirrored horizontally
+//! when the user is on the opponent's side. Moves without a file lunge
+//! at their target.
+
+use std::{fs::read_to_string, path::Path, rc::Rc};
+
+use hashbrown::HashMap;
+use serde::Deserialize;
+
+use pokedex::engine::{
+    graphics::{draw_rectangle, position},
+    tetra::{
+        graphics::{Color, Texture},
+        math::Vec2,
+        Context,
+    },
+    util::WIDTH,
+    EngineContext,
+};
+
+use crate::sound;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveAnimation {
+    /// How far the user lunges towards its target, if it does.
+    #[serde(default)]
+    pub lunge: Option<f32>,
+    /// Texture names and their files, relative to the animation directory.
+    #[serde(default)]
+    pub textures: HashMap<String, String>,
+    pub timeline: Vec<MoveAnimationKey>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveAnimationKey {
+    pub time: f32,
+    pub event: MoveAnimationEvent,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum MoveAnimationEvent {
+    Sprite {
+        texture: String,
+        at: AnimationPoint,
+        duration: f32,
+        #[serde(default)]
+        tweens: Vec<Tween>,
+    },
+    /// Particles spread evenly outwards from a point and fade out.
+    Particles {
+        texture: String,
+        at: AnimationPoint,
+        count: u8,
+        speed: f32,
+        lifetime: f32,
+    },
+    Flash {
+        color: (f32, f32, f32),
+        duration: f32,
+    },
+    Shake {
+        intensity: f32,
+        duration: f32,
+    },
+    Sound(String),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct AnimationPoint {
+    pub anchor: AnimationAnchor,
+    #[serde(default)]
+    pub x: f32,
+    #[serde(default)]
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum AnimationAnchor {
+    User,
+    Target,
+    Screen,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Tween {
+    pub property: TweenProperty,
+    /// Time after the sprite spawns that the tween starts.
+    #[serde(default)]
+    pub delay: f32,
+    pub duration: f32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum TweenProperty {
+    Position(AnimationPoint),
+    Scale(f32),
+    Rotation(f32),
+    Opacity(f32),
+}
+
+pub struct LoadedMoveAnimation {
+    pub animation: MoveAnimation,
+    pub textures: HashMap<String, Texture>,
+}
+
+impl LoadedMoveAnimation {
+    pub fn load(ctx: &mut Context, directory: &Path, file: &Path) -> Option<Self> {
+        let data = match read_to_string(file) {
+            Ok(data) => data,
+            Err(err) => {
+                log::warn!("Could not read move animation at {:?} with error {}", file, err);
+                return None;
+            }
+        };
+        let mut animation: MoveAnimation = match ron::from_str(&data) {
+            Ok(animation) => animation,
+            Err(err) => {
+                log::warn!("Could not parse move animation at {:?} with error {}", file, err);
+                return None;
+            }
+        };
+        // the player walks the timeline in order
+        animation
+            .timeline
+            .sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(std::cmp::Ordering::Equal));
+        let mut textures = HashMap::with_capacity(animation.textures.len());
+        for (name, path) in animation.textures.iter() {
+            match Texture::new(ctx, directory.join(path)) {
+                Ok(texture) => {
+                    textures.insert(name.clone(), texture);
+                }
+                Err(err) => log::warn!(
+                    "Could not load texture {} for move animation at {:?} with error {}",
+                    path,
+                    file,
+                    err
+                ),
+            }
+        }
+        Some(Self {
+            animation,
+            textures,
+        })
+    }
+}
+
+struct AnimationSprite {
+    texture: Texture,
+    origin: Vec2<f32>,
+    start: f32,
+    duration: f32,
+    tweens: Vec<Tween>,
+}
+
+struct AnimationParticle {
+    texture: Texture,
+    origin: Vec2<f32>,
+    direction: Vec2<f32>,
+    start: f32,
+    lifetime: f32,
+}
+
+/// Plays one [`MoveAnimation`] between a user and its target.
+#[derive(Default)]
+pub struct MoveAnimationPlayer {
+    animation: Option<Rc<LoadedMoveAnimation>>,
+    next: usize,
+    elapsed: f32,
+
+    user: Vec2<f32>,
+    target: Vec2<f32>,
+    flip: bool,
+
+    sprites: Vec<AnimationSprite>,
+    particles: Vec<AnimationParticle>,
+    flash: Option<(Color, f32, f32)>,
+    shake: Option<(f32, f32, f32)>,
+}
+
+impl MoveAnimationPlayer {
+    pub fn play(&mut self, animation: &Rc<LoadedMoveAnimation>, user: Vec2<f32>, target: Vec2<f32>, flip: bool) {
+        self.animation = Some(Rc::clone(animation));
+        self.next = 0;
+        self.elapsed = 0.0;
+        self.user = user;
+        self.target = target;
+        self.flip = flip;
+        self.sprites.clear();
+        self.particles.clear();
+        self.flash = None;
+        self.shake = None;
+    }
+
+    fn resolve(&self, point: &AnimationPoint) -> Vec2<f32> {
+        let x = match self.flip && point.anchor != AnimationAnchor::Screen {
+            true => -point.x,
+            false => point.x,
+        };
+        match point.anchor {
+            AnimationAnchor::User => self.user,
+            AnimationAnchor::Target => self.target,
+            AnimationAnchor::Screen => Vec2::zero(),
+        } + Vec2::new(x, point.y)
+    }
+
+    pub fn update(&mut self, ctx: &EngineContext, delta: f32) {
+        if let Some(animation) = self.animation.take() {
+            self.elapsed += delta;
+            while let Some(key) = animation.animation.timeline.get(self.next) {
+                if key.time > self.elapsed {
+                    break;
+                }
+                self.next += 1;
+                let start = key.time;
+                match &key.event {
+                    MoveAnimationEvent::Sprite { texture, at, duration, tweens } => {
+                        if let Some(texture) = animation.textures.get(texture) {
+                            self.sprites.push(AnimationSprite {
+                                texture: texture.clone(),
+                                origin: self.resolve(at),
+                                start,
+                                duration: *duration,
+                                tweens: tweens.clone(),
+                            });
+                        }
+                    }
+                    MoveAnimationEvent::Particles { texture, at, count, speed, lifetime } => {
+                        if let Some(texture) = animation.textures.get(texture) {
+                            let origin = self.resolve(at);
+                            for i in 0..*count {
+                                let angle = i as f32 * std::f32::consts::TAU / *count as f32;
+                                self.particles.push(AnimationParticle {
+                                    texture: texture.clone(),
+                                    origin,
+                                    direction: Vec2::new(angle.cos(), angle.sin()) * *speed,
+                                    start,
+                                    lifetime: *lifetime,
+                                });
+                            }
+                        }
+                    }
+                    MoveAnimationEvent::Flash { color, duration } => {
+                        self.flash = Some((Color::rgb(color.0, color.1, color.2), start, *duration));
+                    }
+                    MoveAnimationEvent::Shake { intensity, duration } => {
+                        self.shake = Some((*intensity, start, *duration));
+                    }
+                    MoveAnimationEvent::Sound(name) => sound::play(ctx, &sound::parse(name)),
+                }
+            }
+
+            let elapsed = self.elapsed;
+            self.sprites.retain(|sprite| elapsed < sprite.start + sprite.duration);
+            self.particles.retain(|particle| elapsed < particle.start + particle.lifetime);
+            if matches!(self.flash, Some((_, start, duration)) if elapsed >= start + duration) {
+                self.flash = None;
+            }
+            if matches!(self.shake, Some((_, start, duration)) if elapsed >= start + duration) {
+                self.shake = None;
+            }
+
+            if self.next < animation.animation.timeline.len()
+                || !self.sprites.is_empty()
+                || !self.particles.is_empty()
+                || self.flash.is_some()
+                || self.shake.is_some()
+            {
+                self.animation = Some(animation);
+            }
+        }
+    }
+
+    pub fn finished(&self) -> bool {
+        self.animation.is_none()
+    }
+
+    /// Offset to draw the scene at while the screen shakes.
+    pub fn shake(&self) -> Vec2<f32> {
+        match self.shake {
+            Some((intensity, ..)) => {
+                let x = (self.elapsed * 60.0).sin() * intensity;
+                let y = (self.elapsed * 45.0).cos() * intensity * 0.5;
+                Vec2::new(x, y)
+            }
+            None => Vec2::zero(),
+        }
+    }
+
+    pub fn draw(&self, ctx: &mut EngineContext) {
+        for sprite in self.sprites.iter() {
+            let time = self.elapsed - sprite.start;
+            let mut origin = sprite.origin;
+            let mut scale = 1.0;
+            let mut rotation = 0.0;
+            let mut opacity = 1.0;
+            for tween in sprite.tweens.iter() {
+                let t = ((time - tween.delay) / tween.duration.max(f32::EPSILON)).clamp(0.0, 1.0);
+                match &tween.property {
+                    TweenProperty::Position(point) => origin = origin + (self.resolve(point) - origin) * t,
+                    TweenProperty::Scale(to) => scale += (to - scale) * t,
+                    TweenProperty::Rotation(to) => rotation += (to - rotation) * t,
+                    TweenProperty::Opacity(to) => opacity += (to - opacity) * t,
+                }
+            }
+            let half = Vec2::new(sprite.texture.width() as f32, sprite.texture.height() as f32) / 2.0;
+            sprite.texture.draw(
+                ctx,
+                position(origin.x, origin.y)
+                    .origin(half)
+                    .rotation(rotation)
+                    .scale(Vec2::new(if self.flip { -scale } else { scale }, scale))
+                    .color(Color::rgba(1.0, 1.0, 1.0, opacity)),
+            );
+        }
+        for particle in self.particles.iter() {
+            let time = self.elapsed - particle.start;
+            let origin = particle.origin + particle.direction * time;
+            particle.texture.draw(
+                ctx,
+                position(origin.x, origin.y)
+                    .color(Color::rgba(1.0, 1.0, 1.0, 1.0 - time / particle.lifetime)),
+            );
+        }
+        if let Some((color, start, duration)) = self.flash {
+            let alpha = 1.0 - (self.elapsed - start) / duration.max(f32::EPSILON);
+            draw_rectangle(
+                ctx,
+                0.0,
+                0.0,
+                WIDTH,
+                160.0,
+                Color::rgba(color.r, color.g, color.b, alpha.max(0.0)),
+            );
+        }
+    }
+}
@@ -23,6 +23,11 @@ impl Flicker {
         }
     }
 
+    /// If the flicker has been started but not yet updated.
+    pub fn waiting(&self) -> bool {
+        self.remaining == Self::TIMES && self.accumulator == 0.0
+    }
+
     pub fn flickering(&self) -> bool {
         self.remaining != 0
     }
@@ -19,24 +19,30 @@ use crate::{
 };
 
 use self::{
+    ailment::AilmentEffect,
     faint::Faint,
     flicker::Flicker,
     spawner::{Spawner, SpawnerState},
+    stages::StatStages,
 };
 
-// mod moves;
+mod moves;
 mod status;
 
-// pub use moves::*;
+pub mod animation;
+
+pub use moves::*;
 pub use status::*;
 pub mod bounce;
 
+pub mod ailment;
 pub mod faint;
 pub mod flicker;
 pub mod spawner;
+pub mod stages;
 
 pub struct PokemonRenderer {
-    // pub moves: MoveRenderer,
+    pub moves: MoveRenderer,
     pub pokemon: Option<Texture>,
     side: PokemonTexture,
 
@@ -45,6 +51,8 @@ pub struct PokemonRenderer {
     pub spawner: Spawner,
     pub faint: Faint,
     pub flicker: Flicker,
+    pub ailment: AilmentEffect,
+    pub stages: StatStages,
 }
 
 impl PokemonRenderer {
@@ -53,14 +61,17 @@ impl PokemonRenderer {
         index: BattleGuiPositionIndex,
         side: PokemonTexture,
     ) -> Self {
+        let pos = Self::position(index);
         Self {
-            // moves: MoveRenderer::new(index.position),
+            moves: MoveRenderer::new(index.position),
             pokemon: None,
             side,
-            pos: Self::position(index),
+            pos,
             spawner: Spawner::new(ctx, None),
             faint: Faint::default(),
             flicker: Flicker::default(),
+            ailment: AilmentEffect::default(),
+            stages: StatStages::default(),
         }
     }
 
@@ -89,6 +100,7 @@ impl PokemonRenderer {
     pub fn new_pokemon(&mut self, dex: &PokedexClientContext, pokemon: Option<PokemonId>) {
         self.spawner.id = pokemon;
         self.pokemon = pokemon.map(|pokemon| dex.pokemon_textures.get(&pokemon, self.side).clone());
+        self.stages.clear();
         self.reset();
     }
 
@@ -127,11 +139,13 @@ impl PokemonRenderer {
                     texture.draw(
                         ctx,
                         position(
-                            pos.x, //+ self.moves.pokemon_x(),
+                            pos.x + self.moves.pokemon_x(),
                             pos.y - texture.height() as f32,
                         )
                         .color(color),
                     );
+                    self.ailment.draw(ctx, pos, texture.width() as f32, texture.height() as f32);
+                    self.stages.draw(ctx, pos, texture.width() as f32, texture.height() as f32);
                 }
             }
         }
@@ -141,12 +155,15 @@ impl PokemonRenderer {
 impl Default for PokemonRenderer {
     fn default() -> Self {
         Self {
+            moves: Default::default(),
             pokemon: Default::default(),
             side: PokemonTexture::Front,
             pos: Default::default(),
             spawner: Spawner::default(),
             faint: Default::default(),
             flicker: Default::default(),
+            ailment: Default::default(),
+            stages: Default::default(),
         }
     }
 }
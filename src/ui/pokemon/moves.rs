@@ -1,137 +1,123 @@
-use pokedex::{
-    engine::tetra::{Context, graphics::DrawParams, math::Vec2},
-    battle_move::script::{BattleAction, BattleActionInstance, BattleActionScriptInstance},
-};
+use std::{fs::read_dir, path::Path, rc::Rc};
+
+use hashbrown::HashMap;
+
+use pokedex::{engine::tetra::Context, moves::MoveId};
 
 use crate::ui::BattleGuiPosition;
 
+use super::animation::LoadedMoveAnimation;
+
+/// Move animations loaded from files, keyed by move.
+pub struct MoveAnimations {
+    pub animations: HashMap<MoveId, Rc<LoadedMoveAnimation>>,
+}
+
+impl MoveAnimations {
+    /// How far the user lunges towards its target when its move has no animation.
+    pub const LUNGE: f32 = 12.0;
+
+    pub fn new() -> Self {
+        Self {
+            animations: HashMap::new(),
+        }
+    }
+
+    pub fn animation(&self, id: &MoveId) -> Option<&Rc<LoadedMoveAnimation>> {
+        self.animations.get(id)
+    }
+
+    /// How far the user of a move lunges towards its target, if it does.
+    pub fn lunge(&self, id: &MoveId) -> Option<f32> {
+        match self.animation(id) {
+            Some(animation) => animation.animation.lunge,
+            None => Some(Self::LUNGE),
+        }
+    }
+
+    /// Loads every `<move id>.ron` animation in a directory.
+    pub fn load_animations(&mut self, ctx: &mut Context, directory: impl AsRef<Path>) {
+        let directory = directory.as_ref();
+        let entries = match read_dir(directory) {
+            Ok(entries) => entries,
+            Err(err) => {
+                log::warn!("Could not read move animation directory {:?} with error {}", directory, err);
+                return;
+            }
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().map(|e| e == "ron").unwrap_or_default() {
+                let id = match path.file_stem().map(|s| s.to_str()).flatten().map(|s| s.parse::<MoveId>().ok()).flatten() {
+                    Some(id) => id,
+                    None => {
+                        log::warn!("Could not get move id from move animation at {:?}", path);
+                        continue;
+                    }
+                };
+                if let Some(animation) = LoadedMoveAnimation::load(ctx, directory, &path) {
+                    self.animations.insert(id, Rc::new(animation));
+                }
+            }
+        }
+    }
+}
+
+/// Moves the user of a move towards its target and back.
+#[derive(Default)]
 pub struct MoveRenderer {
     flip: bool,
-    script: Option<BattleActionScriptInstance>,
+    /// Distance moved so far, how far to move and if the user is returning.
+    lunge: Option<(f32, f32, bool)>,
 }
 
 impl MoveRenderer {
 
+    /// Speed the user lunges at, in pixels per second.
+    const SPEED: f32 = 120.0;
+
     pub fn new(position: BattleGuiPosition) -> Self {
         Self {
             flip: matches!(position, BattleGuiPosition::Top),
-            script: None,
+            lunge: None,
         }
     }
 
-    pub fn init(&mut self, script: BattleActionScriptInstance) {
-        self.script = Some(script);
+    pub fn init(&mut self, distance: f32) {
+        self.lunge = Some((0.0, distance, false));
     }
 
     pub fn update(&mut self, delta: f32) {
-        if let Some(script) = &mut self.script {
-            match &mut script.script.current {
-                None => match script.script.actions.pop_front() {
-                    Some(next) => {
-                        if let Some(instance) = match next {
-                            BattleAction::MoveAndReturnPokemon(len) => Some(BattleActionInstance::MoveAndReturn(0.0, len, false)),
-                            BattleAction::MoveTexture(x, y, speed) => Some(BattleActionInstance::MoveTexture(Vec2::new(x, y), speed)),
-                            BattleAction::SpawnTexture(x, y) => {
-                                script.script.texture = Some(Vec2::new(x, y));
-                                None
-                            },
-                            BattleAction::Wait(wait) => Some(BattleActionInstance::Wait(wait)),
-                            BattleAction::DespawnTexture => {
-                                script.script.texture = None;
-                                None
-                            },
-                        } {
-                            script.script.current = Some(instance);
-                        }
-                    },
-                    None => self.script = None,
-                },
-                Some(current) => match current {
-                    BattleActionInstance::MoveAndReturn(current, max, returning) => {
-                        match *returning {
-                            true => {
-                                *current -= delta * 120.0;
-                                if *current < 0.0 {
-                                    script.script.current = None;
-                                }
-                            }
-                            false => {
-                                *current += delta * 120.0;
-                                if current > max {
-                                    *returning = true;
-                                }
-                            },
-                        }
-                    },
-                    BattleActionInstance::MoveTexture(offset, speed) => {
-                        let delta = delta * 60.0;
-                        match script.script.texture.as_mut() {
-                            Some(pos) => {
-                                if offset.x > 0.0 {
-                                    offset.x -= delta;
-                                    pos.x += delta;
-                                    if offset.x < 0.0 {
-                                        pos.x += 0.0 - offset.x * *speed;
-                                    }
-                                }
-                                if offset.y > 0.0 {
-                                    offset.y -= delta;
-                                    pos.y += delta;
-                                    if offset.y < 0.0 {
-                                        pos.y += 0.0 - offset.y * *speed;
-                                    }
-                                }
-                                if offset.x < 0.0 && offset.y < 0.0 {
-                                    script.script.current = None;
-                                }
-                            },
-                            None => script.script.current = None,
-                        }
+        if let Some((current, max, returning)) = &mut self.lunge {
+            match *returning {
+                true => {
+                    *current -= delta * Self::SPEED;
+                    if *current < 0.0 {
+                        self.lunge = None;
                     }
-                    BattleActionInstance::Wait(remaining) => {
-                        *remaining -= delta;
-                        if *remaining < 0.0 {
-                            script.script.current = None;
-                        }
+                }
+                false => {
+                    *current += delta * Self::SPEED;
+                    if current > max {
+                        *returning = true;
                     }
-                    ,
-                    _ => unreachable!(),
                 },
             }
         }
     }
 
-    pub fn draw(&self, ctx: &mut Context) {
-        if let Some(script) = &self.script {
-            if let Some(texture) = &script.texture {
-                if let Some(position) = script.script.texture {
-                    let params = if self.flip {
-                        DrawParams::default().position(position).scale(Vec2::new(-1.0, 1.0))
-                    } else {
-                        DrawParams::default().position(position)
-                    };
-                    texture.draw(ctx, params);
-                }
-            }
-        }
-    }
-
-    #[deprecated]
+    /// Horizontal offset of the user while it moves towards its target.
     pub fn pokemon_x(&self) -> f32 {
-        match &self.script {
-            Some(script) => match &script.script.current {
-                Some(current) => match current {
-                    BattleActionInstance::MoveAndReturn(c, ..) => *c,
-                    _ => Default::default(),
-                },
-                None => Default::default(),
+        match self.lunge {
+            Some((current, ..)) => match self.flip {
+                true => -current,
+                false => current,
             },
             None => Default::default(),
         }
     }
 
     pub fn finished(&self) -> bool {
-        self.script.is_none()
+        self.lunge.is_none()
     }
 
-}
\ No newline at end of file
+}
@@ -79,6 +79,16 @@ impl Spawner {
         }
     }
 
+    /// Ends the throw at once, playing the cry it would end with.
+    pub fn finish(&mut self, ctx: &EngineContext) {
+        if self.spawning() {
+            if let Some(id) = self.id {
+                play_sound(ctx, &Sound::variant(CRY_ID, Some(id)));
+            }
+            self.spawning = SpawnerState::None;
+        }
+    }
+
     pub fn draw(&self, ctx: &mut Context, origin: Vec2<f32>, texture: &Texture) {
         match self.spawning {
             SpawnerState::Throwing => {
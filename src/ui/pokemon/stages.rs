@@ -0,0 +1,69 @@
+use battle::pokemon::stat::{BattleStatType, Stage};
+use pokedex::engine::{
+    graphics::draw_rectangle,
+    tetra::{graphics::Color, math::Vec2},
+    EngineContext,
+};
+
+/// Stat stages of an active pokemon, along with the arrows played when they change.
+#[derive(Default)]
+pub struct StatStages {
+    pub stages: Vec<(BattleStatType, Stage)>,
+    arrows: f32,
+    rising: bool,
+}
+
+impl StatStages {
+    pub const MAX: Stage = 6;
+    pub const LENGTH: f32 = 0.8;
+
+    const RISE: Color = Color::rgb(88.0 / 255.0, 136.0 / 255.0, 248.0 / 255.0);
+    const FALL: Color = Color::rgb(248.0 / 255.0, 88.0 / 255.0, 88.0 / 255.0);
+
+    /// Adds to the stage of a stat and starts the arrow animation.
+    pub fn add(&mut self, stat: BattleStatType, stage: Stage) {
+        match self.stages.iter_mut().find(|(s, ..)| s == &stat) {
+            Some((.., current)) => *current = (*current + stage).clamp(-Self::MAX, Self::MAX),
+            None => self.stages.push((stat, stage.clamp(-Self::MAX, Self::MAX))),
+        }
+        self.stages.retain(|(.., stage)| *stage != 0);
+        self.rising = stage.is_positive();
+        self.arrows = Self::LENGTH;
+    }
+
+    pub fn update(&mut self, delta: f32) {
+        if self.arrows > 0.0 {
+            self.arrows -= delta;
+            if self.arrows < 0.0 {
+                self.arrows = 0.0;
+            }
+        }
+    }
+
+    pub fn animating(&self) -> bool {
+        self.arrows > 0.0
+    }
+
+    pub fn clear(&mut self) {
+        self.stages.clear();
+        self.arrows = 0.0;
+    }
+
+    /// Draws the arrows over a sprite whose bottom left corner is at `origin`.
+    pub fn draw(&self, ctx: &mut EngineContext, origin: Vec2<f32>, width: f32, height: f32) {
+        if self.animating() {
+            let progress = 1.0 - self.arrows / Self::LENGTH;
+            let (color, direction) = match self.rising {
+                true => (Self::RISE, -1.0),
+                false => (Self::FALL, 1.0),
+            };
+            for i in 0..3 {
+                let x = origin.x + width * (i as f32 + 1.0) / 4.0;
+                let y = origin.y - height / 2.0 + direction * (progress * height / 2.0 - i as f32 * 8.0);
+                draw_rectangle(ctx, x, y, 2.0, 8.0, color);
+                let head = if self.rising { y - 2.0 } else { y + 8.0 };
+                draw_rectangle(ctx, x - 2.0, head, 6.0, 2.0, color);
+            }
+        }
+    }
+}
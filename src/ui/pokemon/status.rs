@@ -1,9 +1,10 @@
 use crate::view::InitUnknownPokemon;
 use pokedex::{
+    ailment::Ailment,
     context::PokedexClientContext,
     engine::{
-        graphics::{draw_text_left, draw_text_right, position},
-        tetra::{graphics::Texture, math::Vec2},
+        graphics::{draw_rectangle, draw_text_left, draw_text_right, position},
+        tetra::{graphics::{Color, Texture}, math::Vec2},
         text::TextColor,
         util::Entity,
         EngineContext,
@@ -41,6 +42,17 @@ struct PokemonStatusData {
     name: String,
     level: (String, Level),
     health: String,
+    ailment: Option<Ailment>,
+    counter: HealthCounter,
+}
+
+/// Counts the displayed health towards its target.
+#[derive(Default)]
+struct HealthCounter {
+    current: f32,
+    target: f32,
+    max: Health,
+    speed: f32,
 }
 
 struct PokemonStatusPos {
@@ -95,6 +107,8 @@ impl PokemonStatusGui {
                     name: pokemon.name().to_owned(),
                     level: Self::level(pokemon.level),
                     health: format!("{}/{}", pokemon.hp(), pokemon.max_hp()),
+                    ailment: pokemon.ailment.map(|a| a.ailment),
+                    counter: HealthCounter::new(pokemon.percent_hp(), pokemon.max_hp()),
                 })
                 .unwrap_or_default(),
             data_pos,
@@ -130,6 +144,8 @@ impl PokemonStatusGui {
                     name: pokemon.name().to_owned(),
                     level: Self::level(pokemon.level),
                     health: String::new(),
+                    ailment: pokemon.ailment.map(|a| a.ailment),
+                    counter: HealthCounter::new(pokemon.hp, 0),
                 })
                 .unwrap_or_default(),
             data_pos,
@@ -159,6 +175,20 @@ impl PokemonStatusGui {
 
     const EXP_OFFSET: Vec2<f32> = Vec2::new(32.0, 33.0);
 
+    const BADGE_OFFSET: f32 = 44.0;
+
+    /// Health below which the bar turns yellow.
+    pub const YELLOW: f32 = 0.5;
+    /// Health below which the bar turns red.
+    pub const RED: f32 = 0.25;
+
+    const HEALTH_FILL_OFFSET: Vec2<f32> = Vec2::new(15.0, 2.0);
+
+    const YELLOW_UPPER: Color = Color::rgb(248.0 / 255.0, 224.0 / 255.0, 56.0 / 255.0);
+    const YELLOW_LOWER: Color = Color::rgb(200.0 / 255.0, 168.0 / 255.0, 8.0 / 255.0);
+    const RED_UPPER: Color = Color::rgb(248.0 / 255.0, 88.0 / 255.0, 56.0 / 255.0);
+    const RED_LOWER: Color = Color::rgb(168.0 / 255.0, 64.0 / 255.0, 72.0 / 255.0);
+
     fn attributes(
         ctx: &BattleGuiContext,
         index: BattleGuiPositionIndex,
@@ -230,6 +260,21 @@ impl PokemonStatusGui {
         )
     }
 
+    fn badge(ailment: Ailment) -> Option<(&'static str, Color)> {
+        match ailment {
+            Ailment::Poison => Some(("PSN", Color::rgb(160.0 / 255.0, 64.0 / 255.0, 160.0 / 255.0))),
+            Ailment::Paralysis => Some(("PAR", Color::rgb(184.0 / 255.0, 184.0 / 255.0, 24.0 / 255.0))),
+            Ailment::Sleep => Some(("SLP", Color::rgb(136.0 / 255.0, 136.0 / 255.0, 120.0 / 255.0))),
+            Ailment::Burn => Some(("BRN", Color::rgb(240.0 / 255.0, 80.0 / 255.0, 48.0 / 255.0))),
+            Ailment::Freeze => Some(("FRZ", Color::rgb(152.0 / 255.0, 216.0 / 255.0, 216.0 / 255.0))),
+            _ => None,
+        }
+    }
+
+    pub fn ailment(&self) -> Option<Ailment> {
+        self.data.active.then(|| self.data.ailment).flatten()
+    }
+
     fn level(level: Level) -> (String, Level) {
         (Self::level_fmt(level), level)
     }
@@ -239,15 +284,42 @@ impl PokemonStatusGui {
     }
 
     pub fn update_hp(&mut self, delta: f32) {
-        self.health.0.update(delta);
+        match self.data.counter.moving() {
+            true => {
+                self.data.counter.update(delta);
+                if !self.small {
+                    self.data.update_health(self.data.counter.current(), self.data.counter.max);
+                }
+                self.health.0.resize(self.data.counter.current, true);
+            }
+            false => self.health.0.update(delta),
+        }
+    }
+
+    /// Moves the health bar and counter straight to the pokemon's health.
+    pub fn finish_hp(&mut self) {
+        let target = self.data.counter.target;
+        self.data.counter.set(target, true);
+        if !self.small {
+            self.data.update_health(self.data.counter.current(), self.data.counter.max);
+        }
+        self.health.0.resize(target, true);
     }
 
-    pub fn update_exp<'d>(&mut self, delta: f32, pokemon: &OwnedPokemon<'d>) {
+    /// If the health bar is below the red threshold and not empty.
+    pub fn low_health(&self) -> bool {
+        self.data.active && self.data.counter.current > 0.0 && self.data.counter.current < Self::RED
+    }
+
+    /// Returns true if the displayed level went up.
+    pub fn update_exp<'d>(&mut self, delta: f32, pokemon: &OwnedPokemon<'d>) -> bool {
+        let mut level_up = false;
         if self.data.active {
             if self.small {
                 self.exp.update_exp(pokemon.level, pokemon, true)
             } else {
                 if self.exp.update(delta) {
+                    level_up = true;
                     self.data.level.1 += 1;
                     self.data.level.0 = Self::level_fmt(self.data.level.1);
                     let base = Pokemon::base_hp(
@@ -257,15 +329,18 @@ impl PokemonStatusGui {
                         self.data.level.1,
                     );
                     self.data.update_health(pokemon.hp(), base);
+                    self.data.counter.max = base;
                 }
             }
             self.health.0.resize(pokemon.percent_hp(), false);
             self.health.0.update(delta);
+            self.data.counter.set(pokemon.percent_hp(), true);
         }
+        level_up
     }
 
     pub fn health_moving(&self) -> bool {
-        self.health.0.is_moving()
+        self.health.0.is_moving() || self.data.counter.moving()
     }
 
     pub fn exp_moving(&self) -> bool {
@@ -341,17 +416,40 @@ impl PokemonStatusGui {
 
                 draw_text_right(ctx, &0, &self.data.level.0, TextColor::Black, x2, y);
 
+                if let Some((badge, color)) = self.data.ailment.map(Self::badge).flatten() {
+                    let x = x2 - Self::BADGE_OFFSET;
+                    draw_rectangle(ctx, x - 1.0, y, 21.0, 9.0, color);
+                    draw_text_left(ctx, &0, badge, TextColor::White, x, y);
+                }
+
                 if !self.small {
                     self.exp.draw(ctx, pos + Self::EXP_OFFSET);
                     draw_text_right(ctx, &0, &self.data.health, TextColor::Black, x2, y + 18.0);
                 }
 
                 self.health.0.draw(ctx, pos + self.health.1);
+                self.draw_health_color(ctx, pos + self.health.1);
             }
         }
     }
 }
 
+impl PokemonStatusGui {
+    fn draw_health_color(&self, ctx: &mut EngineContext, origin: Vec2<f32>) {
+        let percent = self.data.counter.current;
+        if percent < Self::YELLOW {
+            let (upper, lower) = match percent < Self::RED {
+                true => (Self::RED_UPPER, Self::RED_LOWER),
+                false => (Self::YELLOW_UPPER, Self::YELLOW_LOWER),
+            };
+            let origin = origin + Self::HEALTH_FILL_OFFSET;
+            let width = percent.max(0.0) * HealthBar::WIDTH;
+            draw_rectangle(ctx, origin.x, origin.y, width, 1.0, upper);
+            draw_rectangle(ctx, origin.x, origin.y + 1.0, width, 2.0, lower);
+        }
+    }
+}
+
 impl PokemonStatusData {
     pub fn update_view(
         &mut self,
@@ -363,8 +461,12 @@ impl PokemonStatusData {
         if &self.name != pokemon.name() {
             self.name = pokemon.name().to_owned();
         }
+        self.ailment = pokemon.ailment().map(|a| a.ailment);
         if pokemon.level() == previous {
-            health.resize(pokemon.hp(), reset);
+            self.counter.set(pokemon.hp(), reset);
+            if reset {
+                health.resize(pokemon.hp(), reset);
+            }
         }
         if reset {
             self.level = PokemonStatusGui::level(pokemon.level());
@@ -383,6 +485,12 @@ impl PokemonStatusData {
         self.update_view(previous, pokemon, reset, health);
         if exp_active {
             exp.update_exp(previous, pokemon, reset);
+            if pokemon.level() == previous {
+                self.counter.max = pokemon.max_hp();
+                if reset {
+                    self.update_health(pokemon.hp(), pokemon.max_hp());
+                }
+            }
         }
     }
 
@@ -408,3 +516,49 @@ impl Entity for PokemonStatusGui {
         self.alive
     }
 }
+
+impl HealthCounter {
+    /// Shortest time a change in health takes to count.
+    const MIN_TIME: f32 = 0.5;
+    /// Extra time taken when the whole health bar is drained.
+    const FULL_TIME: f32 = 1.0;
+
+    fn new(current: f32, max: Health) -> Self {
+        Self {
+            current,
+            target: current,
+            max,
+            speed: 0.0,
+        }
+    }
+
+    fn set(&mut self, target: f32, reset: bool) {
+        self.target = target;
+        match reset {
+            true => {
+                self.current = target;
+                self.speed = 0.0;
+            }
+            false => {
+                let difference = (target - self.current).abs();
+                self.speed = difference / (Self::MIN_TIME + Self::FULL_TIME * difference);
+            }
+        }
+    }
+
+    fn update(&mut self, delta: f32) {
+        if self.current < self.target {
+            self.current = (self.current + self.speed * delta).min(self.target);
+        } else {
+            self.current = (self.current - self.speed * delta).max(self.target);
+        }
+    }
+
+    fn current(&self) -> Health {
+        (self.current * self.max as f32).round() as Health
+    }
+
+    fn moving(&self) -> bool {
+        self.current != self.target
+    }
+}
@@ -4,7 +4,8 @@ use pokedex::{
     engine::{
         gui::MessageBox,
         tetra::math::Vec2,
-        text::{MessagePage, TextColor},
+        text::{FontId, MessagePage, TextColor},
+        EngineContext,
     },
     item::Item,
     moves::Move,
@@ -12,177 +13,295 @@ use pokedex::{
     types::Effective,
 };
 
+use crate::{catalog::BattleCatalog, options::TextSpeed};
+
+const FONT: FontId = 1;
+/// Width of the message box's text.
+const TEXT_WIDTH: f32 = 218.0;
+const LINES: usize = 2;
+
 pub fn new() -> MessageBox {
-    let mut messagebox = MessageBox::new(super::PANEL_ORIGIN.position + Vec2::new(11.0, 11.0), 1);
+    let mut messagebox = MessageBox::new(super::PANEL_ORIGIN.position + Vec2::new(11.0, 11.0), FONT);
     messagebox.color(TextColor::White);
     messagebox.message.pages.reserve(6);
     messagebox
 }
 
-pub(crate) fn on_move(text: &mut MessageBox, pokemon_move: &Move, user: &str) {
+/// Advances the message box, writing its text and counting down page waits at the text speed.
+pub(crate) fn advance(ctx: &EngineContext, text: &mut MessageBox, speed: TextSpeed, delta: f32) {
+    let delta = match text.waiting() {
+        true => speed.wait(delta),
+        false => speed.delta(delta),
+    };
+    text.update(ctx, delta);
+}
+
+/// Pushes a message from the catalog as a page, one line per line break.
+/// Lines are wrapped to fit the message box by [`layout`].
+pub(crate) fn push(text: &mut MessageBox, message: String, wait: Option<f32>) {
     text.push(MessagePage {
-        lines: vec![format!("{} used {}!", user, pokemon_move.name)],
-        wait: Some(0.5),
+        lines: message.lines().map(str::to_owned).collect(),
+        wait,
     });
 }
 
-pub(crate) fn on_effective(text: &mut MessageBox, effective: &Effective) {
-    if effective != &Effective::Effective {
-        text.push(MessagePage {
-            lines: vec![format!(
-                "It was {}{}",
-                effective,
-                if &Effective::SuperEffective == effective {
-                    "!"
-                } else {
-                    "..."
-                }
-            )],
-            wait: Some(0.5),
-        });
+/// Wraps the lines of every page to the message box width, moving overflow onto new pages.
+/// Pages that already fit are left as they are.
+/// `mark` is the index of a page, and is moved to wherever that page starts once laid out.
+pub(crate) fn layout(ctx: &EngineContext, text: &mut MessageBox, mark: &mut usize) {
+    let pages = &mut text.message.pages;
+    if pages.iter().all(|page| fits(ctx, page)) {
+        return;
     }
+    let count = pages.len();
+    let mut moved = None;
+    let mut laid = Vec::with_capacity(count + 1);
+    for (index, page) in pages.drain(..).enumerate() {
+        if index == *mark {
+            moved = Some(laid.len());
+        }
+        if fits(ctx, &page) {
+            laid.push(page);
+            continue;
+        }
+        let lines: Vec<String> = page
+            .lines
+            .iter()
+            .flat_map(|line| wrap(line, TEXT_WIDTH, |text| measure(ctx, &FONT, text)))
+            .collect();
+        let count = (lines.len() + LINES - 1) / LINES;
+        for (index, lines) in lines.chunks(LINES).enumerate() {
+            laid.push(MessagePage {
+                lines: lines.to_vec(),
+                // only the last page of a message waits on its own
+                wait: match index + 1 == count {
+                    true => page.wait,
+                    false => None,
+                },
+            });
+        }
+    }
+    // marks past the last page stay past it
+    *mark = moved.unwrap_or_else(|| (*mark + laid.len()).saturating_sub(count));
+    *pages = laid;
 }
 
-pub(crate) fn on_crit(text: &mut MessageBox) {
-    text.push(MessagePage {
-        lines: vec!["It was a critical hit!".to_owned()],
-        wait: Some(0.5),
-    })
+fn fits(ctx: &EngineContext, page: &MessagePage) -> bool {
+    page.lines.len() <= LINES && page.lines.iter().all(|line| measure(ctx, &FONT, line) <= TEXT_WIDTH)
+}
+
+/// Splits a line into lines no wider than `width` when measured.
+pub(crate) fn wrap(line: &str, width: f32, measure: impl Fn(&str) -> f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in line.split_whitespace() {
+        let joined = match current.is_empty() {
+            true => word.to_owned(),
+            false => format!("{} {}", current, word),
+        };
+        if measure(&joined) <= width {
+            current = joined;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        // words wider than a line are broken between characters
+        for character in word.chars() {
+            current.push(character);
+            if current.chars().count() > 1 && measure(&current) > width {
+                current.pop();
+                lines.push(std::mem::replace(&mut current, character.to_string()));
+            }
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+pub(crate) fn measure(ctx: &EngineContext, font: &FontId, text: &str) -> f32 {
+    ctx.text_renderer
+        .fonts
+        .get(font)
+        .map(|font| font.text_pixel_length(text))
+        .unwrap_or_default()
+}
+
+pub(crate) fn on_move(text: &mut MessageBox, catalog: &BattleCatalog, pokemon_move: &Move, user: &str) {
+    push(
+        text,
+        catalog.get("move.use", &[("pokemon", &user), ("move", &pokemon_move.name)]),
+        Some(0.5),
+    );
+}
+
+pub(crate) fn on_effective(text: &mut MessageBox, catalog: &BattleCatalog, effective: &Effective) {
+    let variant = match effective {
+        Effective::Effective => return,
+        Effective::SuperEffective => "super",
+        Effective::NotEffective => "not",
+        Effective::Ineffective => "ineffective",
+    };
+    push(text, catalog.variant("move.effective", variant, &[]), Some(0.5));
+}
+
+pub(crate) fn on_crit(text: &mut MessageBox, catalog: &BattleCatalog) {
+    push(text, catalog.get("move.crit", &[]), Some(0.5));
 }
 
 pub(crate) fn on_stat_stage(
     text: &mut MessageBox,
+    catalog: &BattleCatalog,
     pokemon: &str,
     stat: BattleStatType,
     stage: Stage,
 ) {
-    text.push(MessagePage {
-        lines: vec![
-            format!("{}'s {} was", pokemon, stat),
-            format!(
-                "{} by {}!",
-                if stage.is_positive() {
-                    "raised"
-                } else {
-                    "lowered"
-                },
-                stage.abs()
-            ),
-        ],
-        wait: Some(0.5),
-    })
+    let stat = catalog.name("stat.names", &stat.to_string());
+    push(
+        text,
+        catalog.variant(
+            "stat.stage",
+            match stage.is_positive() {
+                true => "raised",
+                false => "lowered",
+            },
+            &[("pokemon", &pokemon), ("stat", &stat), ("stages", &stage.abs())],
+        ),
+        Some(0.5),
+    );
 }
 
-pub(crate) fn on_status(text: &mut MessageBox, pokemon: &str, status: Ailment) {
-    text.push(MessagePage {
-        lines: vec![
-            format!("{} was afflicted", pokemon),
-            format!("with {:?}", status),
-        ],
-        wait: Some(0.5),
-    })
+fn ailment_name(catalog: &BattleCatalog, ailment: Ailment) -> String {
+    catalog.name("ailment.names", &format!("{:?}", ailment))
 }
 
-pub(crate) fn on_miss(text: &mut MessageBox, pokemon: &str) {
-    text.push(MessagePage {
-        lines: vec![format!("{} missed!", pokemon)],
-        wait: Some(0.5),
-    });
+pub(crate) fn on_status(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str, status: Ailment) {
+    let ailment = ailment_name(catalog, status);
+    push(
+        text,
+        catalog.get("ailment.afflict", &[("pokemon", &pokemon), ("ailment", &ailment)]),
+        Some(0.5),
+    );
 }
 
-pub(crate) fn on_item(text: &mut MessageBox, target: &str, item: &Item) {
-    text.push(MessagePage {
-        lines: vec![format!("A {} was used on {}", item.name, target,)],
-        wait: Some(0.5),
-    });
+pub(crate) fn on_ailment_hurt(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str, ailment: Ailment) {
+    push(
+        text,
+        catalog.variant("ailment.hurt", &format!("{:?}", ailment), &[("pokemon", &pokemon)]),
+        Some(0.5),
+    );
 }
 
-fn on_leave(text: &mut MessageBox, leaving: &str) {
-    text.push(MessagePage {
-        lines: vec![format!("Come back, {}!", leaving)],
-        wait: Some(0.5),
-    });
+pub(crate) fn on_ailment_cure(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str, ailment: Ailment) {
+    let name = ailment_name(catalog, ailment);
+    push(
+        text,
+        catalog.variant(
+            "ailment.cure",
+            &format!("{:?}", ailment),
+            &[("pokemon", &pokemon), ("ailment", &name)],
+        ),
+        Some(0.5),
+    );
 }
 
-pub(crate) fn on_switch(text: &mut MessageBox, leaving: &str, coming: &str) {
-    on_leave(text, leaving);
-    on_go(text, coming);
+pub(crate) fn on_miss(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str) {
+    push(text, catalog.get("move.miss", &[("pokemon", &pokemon)]), Some(0.5));
 }
 
-pub(crate) fn on_go(text: &mut MessageBox, coming: &str) {
-    text.push(MessagePage {
-        lines: vec![format!("Go, {}!", coming)],
-        wait: Some(0.5),
-    });
+pub(crate) fn on_item(text: &mut MessageBox, catalog: &BattleCatalog, target: &str, item: &Item) {
+    push(
+        text,
+        catalog.get("item.use", &[("item", &item.name), ("pokemon", &target)]),
+        Some(0.5),
+    );
+}
+
+fn on_leave(text: &mut MessageBox, catalog: &BattleCatalog, leaving: &str) {
+    push(text, catalog.get("switch.leave", &[("pokemon", &leaving)]), Some(0.5));
+}
+
+pub(crate) fn on_switch(text: &mut MessageBox, catalog: &BattleCatalog, leaving: &str, coming: &str) {
+    on_leave(text, catalog, leaving);
+    on_go(text, catalog, coming);
+}
+
+pub(crate) fn on_go(text: &mut MessageBox, catalog: &BattleCatalog, coming: &str) {
+    push(text, catalog.get("switch.go", &[("pokemon", &coming)]), Some(0.5));
 }
 
-pub(crate) fn on_replace(text: &mut MessageBox, user: &str, coming: Option<&str>) {
+pub(crate) fn on_replace(text: &mut MessageBox, catalog: &BattleCatalog, user: &str, coming: Option<&str>) {
     // if let Some(leaving) = leaving {
     //     on_leave(text, leaving);
     // }
     if let Some(coming) = coming {
-        text.push(MessagePage {
-            lines: vec![format!("{} sent out {}!", user, coming)],
-            wait: Some(0.5),
-        });
+        push(
+            text,
+            catalog.get("switch.replace", &[("trainer", &user), ("pokemon", &coming)]),
+            Some(0.5),
+        );
     }
 }
 
-pub(crate) fn on_faint(text: &mut MessageBox, is_wild: bool, is_player: bool, pokemon: &str) {
-    text.push(MessagePage {
-        lines: vec![
-            match is_player {
-                true => pokemon.to_owned(),
-                false => format!(
-                    "{} {}",
-                    match is_wild {
-                        true => "Wild",
-                        false => "Foe",
-                    },
-                    pokemon,
-                ),
-            },
-            String::from("fainted!"),
-        ],
-        wait: Some(1.0),
-    });
+pub(crate) fn on_faint(text: &mut MessageBox, catalog: &BattleCatalog, is_wild: bool, is_player: bool, pokemon: &str) {
+    let variant = match (is_player, is_wild) {
+        (true, ..) => "player",
+        (false, true) => "wild",
+        (false, false) => "foe",
+    };
+    push(text, catalog.variant("faint", variant, &[("pokemon", &pokemon)]), Some(1.0));
 }
 
-pub(crate) fn on_catch(text: &mut MessageBox, pokemon: &str) {
-    text.push(MessagePage {
-        lines: vec![String::from("Gotcha!"), format!("{} was caught!", pokemon)],
-        wait: None,
-    });
+pub(crate) fn on_catch(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str) {
+    push(text, catalog.get("catch", &[("pokemon", &pokemon)]), None);
 }
 
-pub(crate) fn on_gain_exp(
-    text: &mut MessageBox,
-    pokemon: &str,
-    experience: Experience,
-    level: Level,
-) {
-    text.push(MessagePage {
-        lines: vec![
-            format!("{} gained {} EXP. points", pokemon, experience),
-            format!("and {} levels!", level),
-        ],
-        wait: Some(1.0),
-    });
+pub(crate) fn on_gain_exp(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str, experience: Experience) {
+    push(
+        text,
+        catalog.plural(
+            "exp.gain",
+            experience as _,
+            &[("pokemon", &pokemon), ("experience", &experience)],
+        ),
+        Some(1.0),
+    );
 }
 
-// pub(crate) fn on_level_up(text: &mut MessageBox, pokemon: &PokemonInstance, level: Level) {
-//     text.push(MessagePage::new(
-//         vec![
-//             format!("{} grew to", pokemon.name()),
-//             format!("LV. {}!", level),
-//         ],
-//         Some(0.5),
-//     ));
-// }
+pub(crate) fn on_level_up(text: &mut MessageBox, catalog: &BattleCatalog, pokemon: &str, level: Level) {
+    push(
+        text,
+        catalog.get("exp.level_up", &[("pokemon", &pokemon), ("level", &level)]),
+        None,
+    );
+}
 
-pub(crate) fn on_fail(text: &mut MessageBox, lines: Vec<String>) {
-    text.push(MessagePage {
-        lines,
-        wait: Some(0.5),
-    });
+pub(crate) fn on_fail(text: &mut MessageBox, message: String) {
+    push(text, message, Some(0.5));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap;
+
+    fn chars(text: &str) -> f32 {
+        text.chars().count() as f32
+    }
+
+    #[test]
+    fn wraps_between_words() {
+        assert_eq!(wrap("aaa bbb ccc", 7.0, chars), vec!["aaa bbb", "ccc"]);
+    }
+
+    #[test]
+    fn breaks_words_wider_than_a_line() {
+        assert_eq!(wrap("abcdefgh", 3.0, chars), vec!["abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn keeps_empty_lines() {
+        assert_eq!(wrap("", 10.0, chars), vec![""]);
+    }
 }
@@ -2,7 +2,7 @@ use core::ops::{Deref, DerefMut};
 
 use pokedex::{
     context::PokedexClientContext,
-    engine::{graphics::ZERO, tetra::graphics::Color, EngineContext},
+    engine::{graphics::ZERO, tetra::graphics::Color, text::MessagePage, EngineContext},
     pokemon::owned::OwnedPokemon,
     texture::PokemonTexture,
     Identifiable, TrainerId,
@@ -32,6 +32,38 @@ pub struct ActivePlayer<ID, P, const AS: usize> {
     pub player: PlayerParty<ID, usize, P, AS>,
     pub renderer: ActiveRenderer<AS>,
     pub trainer: Option<TrainerId>,
+    pub dialogue: TrainerDialogue,
+    pub prize: TrainerPrize,
+}
+
+/// Lines a trainer says during a battle, supplied by the host.
+#[derive(Default)]
+pub struct TrainerDialogue {
+    /// Said before the trainer challenges the player.
+    pub before: Vec<MessagePage>,
+    /// Said after the trainer loses.
+    pub defeat: Vec<MessagePage>,
+    /// Said once during the battle when their trigger is met.
+    pub during: Vec<(DialogueTrigger, Vec<MessagePage>)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DialogueTrigger {
+    /// The trainer sends out their last pokemon.
+    LastPokemon,
+    /// One of the trainer's pokemon faints for the first time.
+    FirstFaint,
+    /// One of the trainer's active pokemon is low on health.
+    LowHealth,
+    /// A turn starts, counting from 1.
+    Turn(u16),
+}
+
+/// What the player is given for defeating a trainer.
+#[derive(Debug, Default, Clone)]
+pub struct TrainerPrize {
+    pub money: u32,
+    pub badge: Option<String>,
 }
 
 impl<ID, P, const AS: usize> ActivePlayer<ID, P, AS> {
@@ -47,6 +79,8 @@ impl<ID, P, const AS: usize> ActivePlayer<ID, P, AS> {
             player,
             renderer: arr,
             trainer: Default::default(),
+            dialogue: Default::default(),
+            prize: Default::default(),
         }
     }
 
@@ -59,6 +93,10 @@ pub struct ActivePokemonRenderer {
 }
 
 impl ActivePokemonRenderer {
+    pub fn update_ailment(&mut self, delta: f32) {
+        self.pokemon.ailment.update(delta, self.status.ailment());
+    }
+
     pub fn draw(&self, ctx: &mut EngineContext) {
         self.pokemon.draw(ctx, ZERO, Color::WHITE);
         self.status.draw(
@@ -72,7 +110,6 @@ impl ActivePokemonRenderer {
                 1.0
             },
         );
-        // self.renderer.moves.draw(ctx);
     }
 }
 
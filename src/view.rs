@@ -18,7 +18,8 @@ type PartyIndex = usize;
 pub trait PlayerView<'d, ID, const AS: usize> {
     fn id(&self) -> &ID;
 
-    fn name(&self) -> &str;
+    /// The player's name, if they have one.
+    fn name(&self) -> Option<&str>;
 
     fn active(&self, active: Active) -> Option<&dyn GuiPokemonView<'d>>;
 
@@ -38,8 +39,8 @@ impl<'d, ID, A: PI, P: GuiPokemonView<'d>, const AS: usize> PlayerView<'d, ID, A
         &self.id
     }
 
-    fn name(&self) -> &str {
-        self.name.as_deref().unwrap_or("Unknown")
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
     }
 
     fn active(&self, active: usize) -> Option<&dyn GuiPokemonView<'d>> {
@@ -71,6 +72,12 @@ pub trait GuiPokemonView<'d>: PokemonView {
 
     fn name(&self) -> &str;
 
+    /// If the player can see which pokemon this is.
+    /// Unrevealed pokemon have no name, so callers name them from the catalog.
+    fn revealed(&self) -> bool {
+        true
+    }
+
     fn set_level(&mut self, level: Level);
     fn level(&self) -> Level;
 
@@ -78,7 +85,7 @@ pub trait GuiPokemonView<'d>: PokemonView {
     fn hp(&self) -> f32;
 
     fn set_ailment(&mut self, effect: LiveAilment);
-    fn ailment(&mut self) -> Option<&mut LiveAilment>;
+    fn ailment(&self) -> Option<&LiveAilment>;
 
     fn set_exp(&mut self, experience: Experience);
 
@@ -118,8 +125,8 @@ impl<'d> GuiPokemonView<'d> for OwnedPokemon<'d> {
         self.ailment = Some(ailment);
     }
 
-    fn ailment(&mut self) -> Option<&mut LiveAilment> {
-        self.ailment.as_mut()
+    fn ailment(&self) -> Option<&LiveAilment> {
+        self.ailment.as_ref()
     }
 
     fn set_exp(&mut self, experience: Experience) {
@@ -150,10 +157,14 @@ impl<'d> GuiPokemonView<'d> for Option<InitUnknownPokemon<'d>> {
     fn name(&self) -> &str {
         match self {
             Some(u) => u.name(),
-            None => "Unknown",
+            None => "",
         }
     }
 
+    fn revealed(&self) -> bool {
+        self.is_some()
+    }
+
     fn set_level(&mut self, level: Level) {
         if let Some(u) = self.as_mut() {
             u.level = level;
@@ -180,8 +191,8 @@ impl<'d> GuiPokemonView<'d> for Option<InitUnknownPokemon<'d>> {
         }
     }
 
-    fn ailment(&mut self) -> Option<&mut LiveAilment> {
-        self.as_mut().map(|u| u.ailment.as_mut()).flatten()
+    fn ailment(&self) -> Option<&LiveAilment> {
+        self.as_ref().map(|u| u.ailment.as_ref()).flatten()
     }
 
     fn instance(&mut self) -> Option<&mut OwnedPokemon<'d>> {